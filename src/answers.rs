@@ -0,0 +1,49 @@
+use anyhow::*;
+
+use crate::options::Part;
+
+/// Looks up the recorded answer for `part` in an `answers.txt` file.
+///
+/// The file holds one `a: <answer>` / `b: <answer>` line per known-correct answer for
+/// that day's `input.txt`; parts without a line have no recorded answer yet.
+pub fn recorded(answers: &str, part: Part) -> Option<&str> {
+    let key = match part {
+        Part::A => "a",
+        Part::B => "b",
+    };
+    answers
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(k, _)| k.trim() == key)
+        .map(|(_, answer)| answer.trim())
+}
+
+/// Fails if `answer` differs from the recorded answer for `part`.
+pub fn verify(answers: &str, part: Part, answer: &str) -> Result<()> {
+    match recorded(answers, part) {
+        Some(expected) if expected != answer => bail!(
+            "part {:?} answer {} differs from the recorded answer {}",
+            part,
+            answer,
+            expected
+        ),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorded_answers() {
+        let answers = "a: 42\nb: 1337\n";
+        assert_eq!(recorded(answers, Part::A), Some("42"));
+        assert_eq!(recorded(answers, Part::B), Some("1337"));
+        assert_eq!(recorded("a: 42\n", Part::B), None);
+
+        assert!(verify(answers, Part::A, "42").is_ok());
+        assert!(verify(answers, Part::B, "42").is_err());
+        assert!(verify("", Part::B, "42").is_ok());
+    }
+}
@@ -0,0 +1,147 @@
+use anyhow::*;
+use std::{
+    fmt::{Display, Formatter},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{options::Part, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+
+        let iterations = samples.len();
+        let secs = samples.iter().map(Duration::as_secs_f64);
+        let mean = secs.clone().sum::<f64>() / iterations as f64;
+        let variance = secs.map(|s| (s - mean).powi(2)).sum::<f64>() / iterations as f64;
+        let median = match iterations % 2 {
+            0 => (samples[iterations / 2 - 1] + samples[iterations / 2]) / 2,
+            _ => samples[iterations / 2],
+        };
+
+        Some(Self {
+            iterations,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:?}  median {:?}  mean {:?} ± {:?}  ({} runs)",
+            self.min, self.median, self.mean, self.stddev, self.iterations
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct BenchReport {
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "parse:  {}", self.parse)?;
+        for (part, stats) in &self.parts {
+            let label = match part {
+                Part::A => "part 1",
+                Part::B => "part 2",
+            };
+            writeln!(f, "{}: {}", label, stats)?;
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+/// Number of untimed runs before measuring, to warm up caches and the allocator.
+pub fn warmup_iterations(iterations: usize) -> usize {
+    (iterations / 10).max(1)
+}
+
+/// Times `run` on a fresh value from `setup` each iteration; `setup` itself isn't timed.
+fn measure<T, R>(
+    iterations: usize,
+    mut setup: impl FnMut() -> T,
+    mut run: impl FnMut(T) -> Result<R>,
+) -> Result<Stats> {
+    for _ in 0..warmup_iterations(iterations) {
+        black_box(run(setup())?);
+    }
+
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let input = setup();
+        let now = Instant::now();
+        let result = run(input)?;
+        samples.push(now.elapsed());
+        black_box(result);
+    }
+
+    Stats::from_samples(&mut samples).ok_or_else(|| anyhow!("Need at least one iteration"))
+}
+
+/// Runs parse and the requested parts `iterations` times each, cloning the parsed input
+/// for every part run.
+pub fn bench<S: Solution>(
+    data: &str,
+    part: Option<Part>,
+    iterations: usize,
+) -> Result<BenchReport> {
+    let parse = measure(iterations, || data, S::final_parse)?;
+    let parsed = S::final_parse(data)?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![Part::A, Part::B],
+    };
+
+    let parts = parts
+        .into_iter()
+        .map(|part| {
+            let stats = match part {
+                Part::A => measure(iterations, || parsed.clone(), S::part1)?,
+                Part::B => measure(iterations, || parsed.clone(), S::part2)?,
+            };
+            Ok((part, stats))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(BenchReport { parse, parts })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&mut samples).unwrap();
+
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+        assert_eq!(Stats::from_samples(&mut []), None);
+    }
+}
@@ -0,0 +1,238 @@
+use anyhow::*;
+use aoc_2023::{
+    days, diff,
+    options::{Format, InputSource, Options},
+    runner, scaffold, span,
+    util::parse::{diagnostic, eprint_diagnostic},
+    watch::{self, Watcher},
+};
+use fastrand::Rng;
+use std::{path::Path, thread};
+
+const USAGE: &str = "usage:
+    aoc list
+    aoc new <day> [--module <name>]
+    aoc generate <day> [--seed <n>] [--size <n>]
+    aoc diff <day|all> [--seed <n>] [--cases <n>] [--size <n>]
+    aoc run all [a|b]
+    aoc run <day>[_<variant>] [a|b] [--input <path>|-]
+    aoc run <day|all> [a|b] --bench <iterations>
+    aoc run <day|all> [a|b] --verify
+    aoc run <day|all> [a|b] --format json
+    aoc run <day|all> [a|b] --threads <n>
+    aoc run <day|all> [a|b] --timeout <seconds>
+    aoc run <day|all> [a|b] --spans
+    aoc run <day|all> [a|b] --cache [--no-cache]
+    aoc watch <day>[_<variant>] [a|b] [--input <path>] [--timeout <seconds>] [--spans]";
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let registry = days::registry();
+
+    match args.next().as_deref() {
+        Some("list") => {
+            for registration in &registry {
+                println!("{}", registration.name());
+            }
+        }
+        Some("new") => {
+            let day = args.next().ok_or_else(|| anyhow!(USAGE))?;
+            let day = day.parse().map_err(|_| anyhow!("Invalid day: {}", day))?;
+            let module = match args.next().as_deref() {
+                None => None,
+                Some("--module") => Some(args.next().ok_or_else(|| anyhow!(USAGE))?),
+                Some(arg) => match arg.strip_prefix("--module=") {
+                    Some(module) => Some(module.to_string()),
+                    None => bail!("Unknown argument: {}", arg),
+                },
+            };
+            ensure!(args.next().is_none(), USAGE);
+
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+            for path in scaffold::new_day(root, day, module.as_deref())? {
+                println!("created {}", path.display());
+            }
+            println!("registered day{:02} with the runner", day);
+        }
+        Some("diff") => {
+            let spec = args.next().ok_or_else(|| anyhow!(USAGE))?;
+            let selected = runner::select(&registry, &spec)?;
+            let [seed, cases, size] =
+                numeric_flags(args, ["--seed", "--cases", "--size"], [0, 100, 10])?;
+
+            let mut disagreements = 0;
+            for variants in selected.chunk_by(|a, b| a.day == b.day) {
+                if spec == "all" && variants.len() == 1 {
+                    continue;
+                }
+                let inputs = diff::inputs(variants, seed, cases, size as usize);
+                let found = diff::differential(variants, &inputs)?;
+                for disagreement in &found {
+                    println!("day{:02} {}", variants[0].day, disagreement);
+                }
+                if found.is_empty() {
+                    println!(
+                        "day{:02}: {} implementations agree on {} inputs",
+                        variants[0].day,
+                        variants.len(),
+                        inputs.len()
+                    );
+                }
+                disagreements += found.len();
+            }
+            ensure!(
+                disagreements == 0,
+                "{} disagreements between implementations",
+                disagreements
+            );
+        }
+        Some("generate") => {
+            let spec = args.next().ok_or_else(|| anyhow!(USAGE))?;
+            let selected = runner::select(&registry, &spec)?;
+            let [seed, size] = numeric_flags(args, ["--seed", "--size"], [0, 10])?;
+
+            let mut rng = Rng::with_seed(seed);
+            let input = selected
+                .iter()
+                .find_map(|registration| registration.generate(&mut rng, size as usize))
+                .ok_or_else(|| anyhow!("Can't generate inputs for {}", spec))?;
+            // Inputs don't end in a newline, so a redirected one reads back the same
+            print!("{}", input);
+        }
+        Some("run") => {
+            let spec = args.next().ok_or_else(|| anyhow!(USAGE))?;
+            let selected = runner::select(&registry, &spec)?;
+            let options = Options::parse(args)?;
+            ensure!(
+                options.input.is_none() || selected.len() == 1,
+                "--input needs a single day and variant"
+            );
+            options.validate()?;
+            if options.spans {
+                span::enable();
+            }
+
+            if let Some(iterations) = options.bench {
+                for registration in selected {
+                    let data = options.load_input(registration.data)?;
+                    println!("{}", registration.name());
+                    let report = registration
+                        .bench(&data, options.part, iterations)
+                        .inspect_err(|error| eprint_diagnostic(error, &data))?;
+                    println!("{}", report);
+                }
+                return Ok(());
+            }
+
+            let inputs = selected
+                .iter()
+                .map(|registration| options.load_input(registration.data))
+                .collect::<Result<Vec<_>>>()?;
+            let runs = selected
+                .into_iter()
+                .zip(inputs.iter().map(|input| &**input))
+                .collect::<Vec<_>>();
+            let cache = options.cache();
+            let results = runner::run_parallel(
+                &runs,
+                options.part,
+                options.timeout,
+                options.threads,
+                cache.as_ref(),
+            )?;
+
+            for ((registration, data), (_, report)) in runs.iter().zip(&results) {
+                let error = report.as_ref().err();
+                if let Some(diagnostic) = error.and_then(|error| diagnostic(error, data)) {
+                    eprintln!("{}:\n{}", registration.name(), diagnostic);
+                }
+            }
+
+            match options.format {
+                Format::Text => {
+                    print!("{}", runner::summary_table(&results));
+                    for (registration, result) in &results {
+                        if let Result::Ok(report) = result {
+                            print!("{}", runner::span_tree(&registration.name(), report));
+                        }
+                    }
+                }
+                Format::Json => {
+                    for (registration, result) in &results {
+                        let variant = registration.variant;
+                        print!("{}", runner::json_lines(registration.day, variant, result));
+                    }
+                }
+            }
+
+            if options.verify {
+                let mismatches = results
+                    .iter()
+                    .filter_map(|(registration, result)| {
+                        let verified = match result {
+                            Result::Ok(report) => report.verify(registration.answers),
+                            Err(error) => Err(anyhow!("failed to parse: {}", error)),
+                        };
+                        Some(format!("{}: {}", registration.name(), verified.err()?))
+                    })
+                    .collect::<Vec<_>>();
+                ensure!(mismatches.is_empty(), mismatches.join("\n"));
+            }
+        }
+        Some("watch") => {
+            let spec = args.next().ok_or_else(|| anyhow!(USAGE))?;
+            let selected = runner::select(&registry, &spec)?;
+            ensure!(selected.len() == 1, "watch needs a single day and variant");
+            let options = Options::parse(args)?;
+            ensure!(
+                options.bench.is_none() && !options.verify && options.format == Format::Text,
+                "watch doesn't support --bench, --verify or --format json"
+            );
+            let input = match options.input {
+                None => None,
+                Some(InputSource::File(path)) => Some(path),
+                Some(InputSource::Stdin) => bail!("watch can't re-read stdin; pass a file"),
+            };
+            if options.spans {
+                span::enable();
+            }
+
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+            let mut watcher =
+                Watcher::new(root, selected[0], input, options.part, options.timeout)?;
+            for path in watcher.paths() {
+                println!("watching {}", path.display());
+            }
+            loop {
+                print!("{}", watcher.poll());
+                thread::sleep(watch::POLL_INTERVAL);
+            }
+        }
+        _ => bail!(USAGE),
+    }
+
+    Ok(())
+}
+
+/// Parses `--flag <n>` pairs for the given flags, each defaulting to its entry in `defaults`.
+/// Sizes must be positive.
+fn numeric_flags<const N: usize>(
+    mut args: impl Iterator<Item = String>,
+    flags: [&str; N],
+    defaults: [u64; N],
+) -> Result<[u64; N]> {
+    let mut values = defaults;
+    while let Some(flag) = args.next() {
+        let index = flags
+            .iter()
+            .position(|&known| known == flag)
+            .ok_or_else(|| anyhow!("Unknown argument: {}", flag))?;
+        let value = args.next().ok_or_else(|| anyhow!(USAGE))?;
+        values[index] = value
+            .parse()
+            .ok()
+            .filter(|&value| value > 0 || flag != "--size")
+            .ok_or_else(|| anyhow!("Invalid value for {}: {}", flag, value))?;
+    }
+    Ok(values)
+}
@@ -0,0 +1,100 @@
+use anyhow::*;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{options::Part, runner::Registration};
+
+/// Answers saved on disk, so parts already solved for an input aren't solved again.
+///
+/// Each answer is keyed by day, variant, part and a hash of the input, and stored with the
+/// solution's [`crate::Solution::VERSION`]: bumping the version invalidates every answer
+/// saved under the old one. Only answers are cached, never errors. Nothing notices a changed
+/// solution until its version is bumped, so runs only use the cache with `--cache`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Default for Cache {
+    /// The cache under `target/answers`, which `cargo clean` clears along with the build.
+    fn default() -> Self {
+        Self::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("target/answers"))
+    }
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, registration: &Registration, part: Part, data: &str) -> PathBuf {
+        let part = match part {
+            Part::A => 1,
+            Part::B => 2,
+        };
+        self.dir.join(registration.name()).join(format!(
+            "part{}-{:016x}",
+            part,
+            fnv1a(data.as_bytes())
+        ))
+    }
+
+    /// The answer saved for `part` of `data`, unless there is none or it was saved by
+    /// another version of the solution.
+    pub fn get(&self, registration: &Registration, part: Part, data: &str) -> Option<String> {
+        let saved = fs::read_to_string(self.path(registration, part, data)).ok()?;
+        let (version, answer) = saved.split_once('\n')?;
+        (version == registration.version).then(|| answer.to_string())
+    }
+
+    pub fn put(
+        &self,
+        registration: &Registration,
+        part: Part,
+        data: &str,
+        answer: &str,
+    ) -> Result<()> {
+        let path = self.path(registration, part, data);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, format!("{}\n{}", registration.version, answer))
+            .with_context(|| format!("Failed to cache an answer in {}", path.display()))
+    }
+}
+
+/// 64-bit FNV-1a: not cryptographic, but stable across builds, unlike `DefaultHasher`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days, runner};
+
+    #[test]
+    fn invalidated_by_input_and_version() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-test-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+        let registry = days::registry();
+        let mut day01 = *runner::select(&registry, "1")?[0];
+
+        assert_eq!(cache.get(&day01, Part::A, "1abc2"), None);
+        cache.put(&day01, Part::A, "1abc2", "12")?;
+        assert_eq!(cache.get(&day01, Part::A, "1abc2"), Some("12".to_string()));
+        assert_eq!(cache.get(&day01, Part::B, "1abc2"), None);
+        assert_eq!(cache.get(&day01, Part::A, "1abc3"), None);
+
+        day01.version = "2";
+        assert_eq!(cache.get(&day01, Part::A, "1abc2"), None);
+
+        fs::remove_dir_all(dir)?;
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::*;
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// Set by the runner once it has given up on a part, for long-running solvers to poll.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Fails once the token is cancelled, so solvers can bail out with `?`.
+    pub fn check(&self) -> Result<()> {
+        ensure!(!self.is_cancelled(), "Cancelled");
+        Ok(())
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<CancellationToken> = RefCell::default();
+}
+
+/// The token of the part running on this thread, which is never cancelled outside a run
+/// with a timeout. Solvers that spread work over other threads should hand it to them.
+pub fn current() -> CancellationToken {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Makes `token` the current token while `f` runs on this thread.
+pub fn with_token<T>(token: CancellationToken, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<CancellationToken>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            if let Some(previous) = self.0.take() {
+                CURRENT.with(|current| *current.borrow_mut() = previous);
+            }
+        }
+    }
+
+    let previous = CURRENT.with(|current| current.replace(token));
+    let _restore = Restore(Some(previous));
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn current_token() {
+        assert!(current().check().is_ok());
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let inner = with_token(token, || current().check());
+
+        assert!(inner.is_err());
+        assert!(current().check().is_ok());
+    }
+}
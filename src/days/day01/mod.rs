@@ -0,0 +1,103 @@
+use crate::*;
+use anyhow::{anyhow, Result};
+use fastrand::Rng;
+use itertools::Itertools;
+use std::str::Lines;
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Lines<'a>;
+    type Answer = u32;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample1.txt");
+    const SAMPLE_DATA_B: &'static str = include_str!("sample2.txt");
+
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 142;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 281;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<u32> {
+        solve(input, &[])
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<u32> {
+        solve(
+            input,
+            &[
+                ("one", 1),
+                ("two", 2),
+                ("three", 3),
+                ("four", 4),
+                ("five", 5),
+                ("six", 6),
+                ("seven", 7),
+                ("eight", 8),
+                ("nine", 9),
+            ],
+        )
+    }
+
+    fn parse(input: &str) -> IResult<Self::Parsed<'_>> {
+        Ok(input.lines())
+    }
+
+    /// `size` lines of calibration document, each with at least one digit.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const WORDS: [&str; 9] = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        let line = |rng: &mut Rng| {
+            let mut pieces = (0..rng.usize(0..6))
+                .map(|_| match rng.u8(0..3) {
+                    0 => (0..rng.usize(1..=4)).map(|_| rng.lowercase()).collect(),
+                    1 => WORDS[rng.usize(..WORDS.len())].to_string(),
+                    _ => rng.char('1'..='9').to_string(),
+                })
+                .collect::<Vec<String>>();
+            pieces.insert(rng.usize(..=pieces.len()), rng.char('1'..='9').to_string());
+            pieces.concat()
+        };
+        Some((0..size).map(|_| line(rng)).join("\n"))
+    }
+}
+
+fn solve(lines: Lines, digit_words: &[(&str, u32)]) -> Result<u32> {
+    let line_to_calibration_value = |line: &str| {
+        let err = || anyhow!("Couldn't find a digit in line '{line}'");
+
+        let digit_at_i = |i| {
+            let digit = line[i..i + 1].parse().ok();
+            let match_word = |&(digit, val)| line[i..].starts_with(digit).then_some(val);
+            digit.or_else(|| digit_words.iter().find_map(match_word))
+        };
+
+        let first = (0..line.len()).find_map(digit_at_i).ok_or_else(err)?;
+        let last = (0..line.len()).rev().find_map(digit_at_i).ok_or_else(err)?;
+
+        Ok(first * 10 + last)
+    };
+    lines.map(line_to_calibration_value).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> Result<()> {
+        Day::test_b()
+    }
+
+    sample_tests! {
+        Day;
+        b_sample1: B, include_str!("sample1.txt") => 142;
+    }
+}
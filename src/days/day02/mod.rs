@@ -0,0 +1,125 @@
+use crate::{util::parse::Source, *};
+use anyhow::*;
+use fastrand::Rng;
+use itertools::Itertools;
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Vec<Game>;
+    type Answer = u32;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 8;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 2286;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<u32> {
+        const TARGET: Revealed = Revealed {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        let is_good_game = |game: &Game| {
+            let revealed = &game.revealed;
+            revealed.red <= TARGET.red
+                && revealed.blue <= TARGET.blue
+                && revealed.green <= TARGET.green
+        };
+        Ok(input
+            .into_iter()
+            .filter(is_good_game)
+            .map(|game| game.id)
+            .sum())
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<u32> {
+        let power = |game: Game| {
+            let revealed = &game.revealed;
+            revealed.red as u32 * revealed.blue as u32 * revealed.green as u32
+        };
+        Ok(input.into_iter().map(power).sum())
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        Source(data).lines(parse_game)
+    }
+
+    /// `size` games of up to six rounds.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let round = |rng: &mut Rng| {
+            let mut colors = ["red", "green", "blue"];
+            rng.shuffle(&mut colors);
+            colors[..rng.usize(1..=3)]
+                .iter()
+                .map(|color| format!("{} {}", rng.u8(1..=20), color))
+                .join(", ")
+        };
+        let game = |rng: &mut Rng, id| {
+            let rounds = (0..rng.usize(1..=6)).map(|_| round(rng)).join("; ");
+            format!("Game {}: {}", id, rounds)
+        };
+        Some((1..=size).map(|id| game(rng, id)).join("\n"))
+    }
+}
+
+fn parse_game(input: &str) -> Result<Game> {
+    let src = Source(input);
+    let (id_str, revealed_str) = src.split_once(input, ": ")?;
+    let id = src.parse(src.strip_prefix(id_str, "Game ")?, "a game id")?;
+    let revealed = revealed(src, revealed_str)?;
+    Ok(Game { id, revealed })
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Game {
+    id: u32,
+    revealed: Revealed,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Revealed {
+    red: u8,
+    blue: u8,
+    green: u8,
+}
+
+fn revealed(src: Source, input: &str) -> Result<Revealed> {
+    let rounds = input.split("; ");
+
+    let update_max = |revealed: &mut Revealed, round_description: &str| {
+        let colors = round_description.split(", ");
+        for color in colors {
+            let (count_str, color) = src.split_once(color, " ")?;
+            let count = src.parse(count_str, "a cube count")?;
+            match color {
+                "red" => revealed.red = revealed.red.max(count),
+                "blue" => revealed.blue = revealed.blue.max(count),
+                "green" => revealed.green = revealed.green.max(count),
+                _ => return Err(src.error(color, "a color").into()),
+            }
+        }
+        Ok(revealed.clone())
+    };
+
+    rounds.fold(Ok(Revealed::default()), |acc, round| {
+        acc.and_then(|mut revealed| update_max(&mut revealed, round))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
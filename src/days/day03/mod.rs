@@ -0,0 +1,88 @@
+use crate::*;
+use anyhow::*;
+use fastrand::Rng;
+
+pub mod schematic;
+use itertools::Itertools;
+pub use schematic::*;
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Schematic;
+    type Answer = u32;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 4361;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 467835;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(input
+            .numbers
+            .iter()
+            .filter(|number| input.is_number_touching_component(number))
+            .map(|number| number.n)
+            .sum())
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let map = &input.raw_schematic.map;
+        let gears = map
+            .iter()
+            .filter_map(|(coord, &c)| (c == b'*').then_some(coord));
+
+        let numbers = gears
+            .filter_map(|coords| {
+                input
+                    .numbers
+                    .iter()
+                    .filter(|number| number.intersects(map, coords))
+                    .collect_tuple::<(_, _)>()
+            })
+            .map(|(a, b)| a.n * b.n)
+            .sum();
+
+        Ok(numbers)
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        data.parse()
+    }
+
+    /// A `size` by `size` schematic.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const SYMBOLS: &[u8] = b"*#+$/=%@&-";
+        let row = |rng: &mut Rng| {
+            let mut row = String::new();
+            while row.len() < size {
+                match rng.u8(0..10) {
+                    0..=1 => row.push_str(&rng.u32(1..1000).to_string()),
+                    2 => row.push(SYMBOLS[rng.usize(..SYMBOLS.len())] as char),
+                    _ => (),
+                }
+                row.push('.');
+            }
+            row.truncate(size);
+            row
+        };
+        Some((0..size).map(|_| row(rng)).join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
@@ -0,0 +1,86 @@
+use anyhow::*;
+use std::{ops::RangeInclusive, str::FromStr};
+
+use crate::util::{grid::Grid, point::Point};
+
+#[derive(Debug, Clone)]
+pub struct RawSchematic {
+    pub map: Grid<u8>,
+}
+
+impl RawSchematic {
+    fn is_component(c: u8) -> bool {
+        !c.is_ascii_digit() && c != b'.'
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SchematicNumber {
+    y: i32,
+    x: RangeInclusive<i32>,
+    pub n: u32,
+}
+
+impl SchematicNumber {
+    /// The cells around each of the number's digits, including its other digits.
+    pub fn adjacent<'a>(&'a self, map: &'a Grid<u8>) -> impl Iterator<Item = (Point, &'a u8)> {
+        self.x
+            .clone()
+            .flat_map(move |x| map.neighbors8(Point::new(x, self.y)))
+    }
+
+    pub fn intersects(&self, map: &Grid<u8>, coords: Point) -> bool {
+        self.adjacent(map).any(|(coord, _)| coord == coords)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    pub raw_schematic: RawSchematic,
+    pub numbers: Vec<SchematicNumber>,
+}
+
+impl Schematic {
+    pub fn is_number_touching_component(&self, number: &SchematicNumber) -> bool {
+        number
+            .adjacent(&self.raw_schematic.map)
+            .any(|(_, &c)| RawSchematic::is_component(c))
+    }
+}
+
+impl FromStr for Schematic {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let map = Grid::parse(s)?;
+        let numbers = find_numbers(&map);
+        Ok(Self {
+            raw_schematic: RawSchematic { map },
+            numbers,
+        })
+    }
+}
+
+fn find_numbers(map: &Grid<u8>) -> Vec<SchematicNumber> {
+    let mut numbers = Vec::new();
+    for y in 0..map.height {
+        let mut x = 0;
+        while x < map.width {
+            let start = x;
+            let mut n = 0;
+            while let Some(c) = map.get(Point::new(x, y)).filter(|c| c.is_ascii_digit()) {
+                n = n * 10 + (c - b'0') as u32;
+                x += 1;
+            }
+            if x > start {
+                numbers.push(SchematicNumber {
+                    y,
+                    x: start..=x - 1,
+                    n,
+                });
+            } else {
+                x += 1;
+            }
+        }
+    }
+    numbers
+}
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use crate::util::parse::Source;
 use anyhow::*;
 use itertools::Itertools;
 
@@ -26,9 +27,9 @@ impl FromStr for Card {
     type Err = anyhow::Error;
 
     fn from_str(value: &str) -> Result<Self> {
-        let err = || anyhow!("Parse Error :(");
-        let (_, numbers) = value.split_once(": ").ok_or_else(err)?;
-        let (winning, owned) = numbers.split_once(" | ").ok_or_else(err)?;
+        let src = Source(value);
+        let (_, numbers) = src.split_once(value, ": ")?;
+        let (winning, owned) = src.split_once(numbers, " | ")?;
         let winners = winning
             .split_ascii_whitespace()
             .filter(|n| owned.split_ascii_whitespace().contains(n))
@@ -45,8 +46,6 @@ impl FromStr for Cards {
     type Err = anyhow::Error;
 
     fn from_str(value: &str) -> Result<Self> {
-        Ok(Cards(
-            value.lines().map(Card::from_str).collect::<Result<_>>()?,
-        ))
+        Ok(Cards(Source(value).lines(Card::from_str)?))
     }
 }
@@ -0,0 +1,75 @@
+use crate::*;
+use anyhow::*;
+pub use card::{Card, Cards};
+use fastrand::Rng;
+use itertools::Itertools;
+
+pub struct Day;
+
+pub mod card;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Cards;
+    type Answer = u32;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 13;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 30;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(input.0.iter().map(Card::card_score).sum::<u32>())
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        // 1 of each card we start with
+        let mut cards_won = vec![1; input.0.len()];
+
+        for (idx, card) in input.0.iter().enumerate() {
+            let this_card_winnings = card.number_of_winning_cards();
+            let id: usize = idx + 1;
+            // cards starting from next limit by card winning
+            for winning_idx in id..id + this_card_winnings {
+                // increase by number of copies of this card we've won so far
+                cards_won[winning_idx as usize] += cards_won[idx];
+            }
+        }
+
+        Ok(cards_won.iter().sum())
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        data.parse()
+    }
+
+    /// `size` cards, none of which win copies of cards past the end of the table.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let numbers = |numbers: &[u32]| numbers.iter().map(|n| format!("{:>2}", n)).join(" ");
+        let card = |rng: &mut Rng, id: usize| {
+            let mut pool = (1..100).collect_vec();
+            rng.shuffle(&mut pool);
+            let (winning, rest) = pool.split_at(10);
+            let wins = rng.usize(0..=(size - id).min(winning.len()));
+            let mut owned = [&winning[..wins], &rest[..25 - wins]].concat();
+            rng.shuffle(&mut owned);
+            format!("Card {:>3}: {} | {}", id, numbers(winning), numbers(&owned))
+        };
+        Some((1..=size).map(|id| card(rng, id)).join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
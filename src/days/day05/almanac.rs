@@ -1,5 +1,6 @@
+use crate::util::parse::Source;
 use anyhow::*;
-use itertools::{process_results, Itertools};
+use itertools::Itertools;
 use std::{ops::Range, str::FromStr};
 
 #[derive(Debug, Clone)]
@@ -77,16 +78,15 @@ impl FromStr for Almanac {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let err = || anyhow!("invalid input");
-        let (seeds_line, rest) = s.split_once("\n\n").ok_or_else(err)?;
-        let initial_seeds = seeds_line
-            .strip_prefix("seeds: ")
-            .ok_or_else(err)?
+        let src = Source(s);
+        let (seeds_line, rest) = src.split_once(s, "\n\n")?;
+        let initial_seeds = src
+            .strip_prefix(seeds_line, "seeds: ")?
             .split_ascii_whitespace()
-            .map(|line| line.parse().map_err(|_| err()))
+            .map(|seed| src.parse(seed, "a seed number"))
             .try_collect()?;
 
-        let range_chain = rest.split("\n\n").map(parse_map_block).try_collect()?;
+        let range_chain = src.parse_each(rest.split("\n\n"), parse_map_block)?;
 
         Ok({
             Almanac {
@@ -98,15 +98,12 @@ impl FromStr for Almanac {
 }
 
 fn parse_map_block(block: &str) -> Result<RangeBlock> {
-    let err = || anyhow!("invalid input");
-
     let lines = block.lines().skip(1);
 
     let parse_range = |line: &str| -> Result<RangeMapping> {
-        let (to_start, from_start, length) =
-            process_results(line.split_ascii_whitespace().map(u64::from_str), |it| {
-                it.collect_tuple().ok_or_else(err)
-            })??;
+        let src = Source(line);
+        let [to_start, from_start, length]: [u64; 3] =
+            src.parse_fields(line, line.split_ascii_whitespace(), "a number")?;
 
         Ok({
             RangeMapping {
@@ -116,7 +113,7 @@ fn parse_map_block(block: &str) -> Result<RangeBlock> {
         })
     };
 
-    Ok(RangeBlock(lines.map(parse_range).try_collect()?))
+    Ok(RangeBlock(Source(block).parse_each(lines, parse_range)?))
 }
 
 pub fn map_range_chain(
@@ -138,3 +135,42 @@ pub fn map_range_chain(
     }
     overlaps.into_iter().chain(queue)
 }
+
+/// Maps each value in `range` by itself, where [`map_range_chain`] splits whole ranges at
+/// the mappings' edges.
+#[cfg(test)]
+fn map_range_naive(block: &RangeBlock, range: Range<u64>) -> Vec<u64> {
+    range.map(|value| block.map(value)).sorted().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fastrand::Rng;
+
+    #[test]
+    fn map_range_chain_matches_mapping_each_value() {
+        let mut rng = Rng::with_seed(5);
+        for _ in 0..500 {
+            let mappings = super::super::disjoint_ranges(&mut rng, 4, 64)
+                .into_iter()
+                .map(|range| RangeMapping {
+                    offset: rng.i64(0..64) - range.start as i64,
+                    range,
+                })
+                .collect();
+            let block = RangeBlock(mappings);
+            let (start, end) = (rng.u64(..64), rng.u64(..64));
+            let range = start.min(end)..start.max(end);
+
+            let fast = map_range_chain(&block, range.clone()).flatten().sorted();
+            assert_eq!(
+                fast.collect_vec(),
+                map_range_naive(&block, range.clone()),
+                "{:?} through {:?}",
+                range,
+                block
+            );
+        }
+    }
+}
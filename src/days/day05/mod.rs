@@ -0,0 +1,120 @@
+use crate::*;
+pub use almanac::{map_range_chain, Almanac};
+use anyhow::*;
+use fastrand::Rng;
+use itertools::Itertools;
+use std::ops::Range;
+
+pub struct Day;
+
+pub mod almanac;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Almanac;
+    type Answer = u64;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 35;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 46;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        input
+            .initial_seeds
+            .iter()
+            .map(|seed| {
+                input
+                    .range_chain
+                    .iter()
+                    .fold(*seed, |value, block| block.map(value))
+            })
+            .min()
+            .ok_or_else(|| anyhow!("no seeds"))
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let ranges = input.seed_ranges();
+
+        let all_possible_ranges = ranges.flat_map(|range| {
+            input.range_chain.iter().fold(vec![range], |acc, block| {
+                acc.into_iter()
+                    .flat_map(|range| map_range_chain(block, range))
+                    .collect()
+            })
+        });
+
+        all_possible_ranges
+            .map(|r| r.start)
+            .min()
+            .ok_or_else(|| anyhow!("empty seeds"))
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        data.parse()
+    }
+
+    /// `size` seed ranges, and `size` ranges in each map.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const LIMIT: u64 = u32::MAX as u64;
+        const CATEGORIES: [&str; 8] = [
+            "seed",
+            "soil",
+            "fertilizer",
+            "water",
+            "light",
+            "temperature",
+            "humidity",
+            "location",
+        ];
+
+        let seeds = disjoint_ranges(rng, size, LIMIT)
+            .into_iter()
+            .map(|range| format!("{} {}", range.start, range.end - range.start))
+            .join(" ");
+        let maps = CATEGORIES.iter().tuple_windows().map(|(from, to)| {
+            let ranges = disjoint_ranges(rng, size, LIMIT).into_iter().map(|range| {
+                let length = range.end - range.start;
+                format!("{} {} {}", rng.u64(..LIMIT - length), range.start, length)
+            });
+            format!(
+                "{}-to-{} map:\n{}",
+                from,
+                to,
+                ranges.collect_vec().join("\n")
+            )
+        });
+        Some(format!(
+            "seeds: {}\n\n{}",
+            seeds,
+            maps.collect_vec().join("\n\n")
+        ))
+    }
+}
+
+/// Up to `count` non-empty, non-overlapping ranges below `limit`, in order.
+fn disjoint_ranges(rng: &mut Rng, count: usize, limit: u64) -> Vec<Range<u64>> {
+    let mut bounds = (0..count * 2).map(|_| rng.u64(..limit)).collect_vec();
+    bounds.sort_unstable();
+    bounds.dedup();
+    bounds
+        .chunks_exact(2)
+        .map(|pair| pair[0]..pair[1])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
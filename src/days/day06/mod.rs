@@ -0,0 +1,138 @@
+use crate::{util::parse::Source, *};
+use anyhow::*;
+use fastrand::Rng;
+use itertools::{process_results, Itertools};
+
+#[derive(Debug, Clone, Default)]
+pub struct Race {
+    time: usize,
+    distance_to_beat: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct RaceGame {
+    individual_races: Vec<Race>,
+    big_race: Race,
+}
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = RaceGame;
+    type Answer = usize;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 288;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 71503;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let err = || anyhow!("Part 1 error :(");
+        process_results(
+            input
+                .individual_races
+                .into_iter()
+                .map(|race| race.winning_times().ok_or_else(err)),
+            |it| it.product(),
+        )
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let err = || anyhow!("Part 2 error :(");
+        let big_race = input.big_race;
+
+        big_race.winning_times().ok_or_else(err)
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        let src = Source(data);
+        let (time_str, distance_str) = src.split_once(data, "\n")?;
+        let time_list = src.strip_prefix(time_str, "Time: ")?;
+        let distance_list = src.strip_prefix(distance_str, "Distance: ")?;
+        let times = time_list.split_ascii_whitespace();
+        let distances = distance_list.split_ascii_whitespace();
+
+        let individual_times = times.clone().map(|time| src.parse(time, "a time"));
+        let individual_distances = distances
+            .clone()
+            .map(|distance| src.parse(distance, "a distance"));
+
+        let zip = individual_times
+            .zip(individual_distances)
+            .map(|(time, distance)| Ok((time?, distance?)));
+
+        let races = process_results(zip, |it| {
+            it.map(move |(time, distance_to_beat)| Race {
+                time,
+                distance_to_beat,
+            })
+            .collect()
+        })?;
+
+        // The joined number isn't a slice of the input, so point at the whole list instead
+        let joined = |list: &str, mut numbers: std::str::SplitAsciiWhitespace, expected| {
+            let joined = numbers.join("");
+            joined.parse().map_err(|_| src.error(list.trim(), expected))
+        };
+        let joined_time = joined(time_list, times, "times that fit in one number")?;
+        let joined_distance = joined(distance_list, distances, "distances that fit in one number")?;
+
+        Ok(RaceGame {
+            individual_races: races,
+            big_race: Race {
+                time: joined_time,
+                distance_to_beat: joined_distance,
+            },
+        })
+    }
+
+    /// `size` races, up to four: any more and the one long race's record overflows.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let (times, records): (Vec<_>, Vec<_>) = (0..size.min(4))
+            .map(|_| {
+                let time = rng.usize(7..100);
+                let best = (time / 2) * (time - time / 2);
+                (time, rng.usize(1..best))
+            })
+            .unzip();
+        let row = |numbers: &[usize]| numbers.iter().map(|n| format!("{:>5}", n)).join(" ");
+        Some(format!(
+            "Time:     {}\nDistance: {}",
+            row(&times),
+            row(&records)
+        ))
+    }
+}
+
+impl Race {
+    fn minimal_time_to_beat(&self) -> Option<usize> {
+        (0..=self.time / 2).find(|hold_time| {
+            let travel_time = self.time - hold_time;
+            hold_time * travel_time > self.distance_to_beat
+        })
+    }
+
+    fn winning_times(&self) -> Option<usize> {
+        let start = self.minimal_time_to_beat()?;
+        let end = self.time - start;
+
+        Some(end - start + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
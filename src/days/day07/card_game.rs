@@ -1,3 +1,4 @@
+use crate::util::parse::Source;
 use anyhow::*;
 use std::cmp::Ordering;
 
@@ -19,25 +20,25 @@ pub enum Card {
     Ace = 14,
 }
 
-impl TryFrom<char> for Card {
+impl TryFrom<&str> for Card {
     type Error = anyhow::Error;
 
-    fn try_from(s: char) -> Result<Self, Self::Error> {
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
         Ok(match s {
-            '2' => Card::Two,
-            '3' => Card::Three,
-            '4' => Card::Four,
-            '5' => Card::Five,
-            '6' => Card::Six,
-            '7' => Card::Seven,
-            '8' => Card::Eight,
-            '9' => Card::Nine,
-            'T' => Card::Ten,
-            'J' => Card::Jack,
-            'Q' => Card::Queen,
-            'K' => Card::King,
-            'A' => Card::Ace,
-            _ => Err(anyhow!("Invalid card: {}", s))?,
+            "2" => Card::Two,
+            "3" => Card::Three,
+            "4" => Card::Four,
+            "5" => Card::Five,
+            "6" => Card::Six,
+            "7" => Card::Seven,
+            "8" => Card::Eight,
+            "9" => Card::Nine,
+            "T" => Card::Ten,
+            "J" => Card::Jack,
+            "Q" => Card::Queen,
+            "K" => Card::King,
+            "A" => Card::Ace,
+            _ => return Err(Source(s).error(s, "a card").into()),
         })
     }
 }
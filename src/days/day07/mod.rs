@@ -0,0 +1,96 @@
+use crate::{util::parse::Source, *};
+use anyhow::*;
+pub use card_game::*;
+use fastrand::Rng;
+use itertools::Itertools;
+
+pub struct Day;
+
+pub mod card_game;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Vec<Game>;
+    type Answer = u32;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 6440;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 5905;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(mut input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        input.sort();
+
+        Ok(play(input))
+    }
+
+    fn part2(mut input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        for card in input.iter_mut() {
+            card.playing_with_jokers = true;
+        }
+        input.sort();
+
+        Ok(play(input))
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        Source(data).lines(|line| {
+            let src = Source(line);
+            let (cards_str, bid) = src.split_once(line, " ")?;
+            let labels = cards_str.split_inclusive(|_: char| true);
+            let labels = src.fields::<5>(cards_str, labels, "a card")?;
+
+            let mut cards = [Card::Ace; 5];
+            for (card, label) in cards.iter_mut().zip(labels) {
+                *card = Card::try_from(label).map_err(|error| src.within(error, label))?;
+            }
+
+            let bid = src.parse(bid, "a bid")?;
+            Ok(Game {
+                cards,
+                bid,
+                playing_with_jokers: false,
+            })
+        })
+    }
+
+    /// `size` hands, drawn from a few labels each so that pairs and sets are common.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let hand = |rng: &mut Rng| {
+            let mut labels = "AKQJT98765432".chars().collect_vec();
+            rng.shuffle(&mut labels);
+            labels.truncate(rng.usize(1..=5));
+            let cards: String = (0..5).map(|_| labels[rng.usize(..labels.len())]).collect();
+            format!("{} {}", cards, rng.u32(1..=1000))
+        };
+        Some((0..size).map(|_| hand(rng)).join("\n"))
+    }
+}
+
+fn play(input: Vec<Game>) -> u32 {
+    input
+        .into_iter()
+        .enumerate()
+        .map(|(i, game)| {
+            let bid = game.bid;
+            let score = bid * (i + 1) as u32;
+            score
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
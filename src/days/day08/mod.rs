@@ -0,0 +1,127 @@
+use crate::{
+    util::{generate, parse::Source},
+    *,
+};
+use anyhow::*;
+use fastrand::Rng;
+use itertools::{process_results, Itertools};
+pub use network::*;
+use num::Integer;
+
+pub struct Day;
+
+pub mod network;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Network<'a>;
+    type Answer = u64;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_DATA_B: &'static str = include_str!("sample2.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 6;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 6;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        input.steps_to_reach("AAA", |node| node == "ZZZ")
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let nodes = input.nodes.iter().filter(|(id, _)| id.ends_with("A"));
+
+        let visits = nodes.map(|(id, _)| {
+            let steps = input.steps_to_reach(id, |node| node.ends_with("Z"))?;
+            Ok(steps)
+        });
+
+        process_results(visits, |it| it.reduce(|a, b| a.lcm(&b)).unwrap())
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        let src = Source(data);
+        let (instruction_str, nodes_str) = src.split_once(data, "\n\n")?;
+        let instructions = instruction_str.split_inclusive(|_: char| true);
+        let instructions = src.parse_each(instructions, Instruction::try_from)?;
+
+        let nodes = src
+            .parse_each(nodes_str.lines(), Node::try_from)?
+            .into_iter()
+            .map(|node| (node.id, node))
+            .collect();
+
+        Ok(Network {
+            instructions,
+            nodes,
+        })
+    }
+
+    /// `size` instructions, and a few ghosts whose paths loop from their `Z` node back to
+    /// where their start led, a whole number of instruction cycles long, so the LCM in part 2
+    /// holds. `AAA` to `ZZZ` is one of them.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let instructions = (0..size)
+            .map(|_| if rng.bool() { 'L' } else { 'R' })
+            .collect_vec();
+        let lengths = (0..rng.usize(1..=6))
+            .map(|_| size * rng.usize(1..=5))
+            .collect_vec();
+
+        // Other nodes never end in `A` or `Z`, and get longer names if three letters run out
+        let count = lengths.iter().sum::<usize>();
+        let len = (3..).find(|&len| 24usize.pow(len) >= 2 * count).unwrap();
+        let letter = |rng: &mut Rng| rng.char('B'..='Y');
+        let mut names = generate::words(rng, count, len as usize, letter, &[]).into_iter();
+        let prefixes = generate::words(rng, lengths.len(), len as usize - 1, letter, &[]);
+
+        let mut nodes = vec![];
+        for (ghost, (&length, prefix)) in lengths.iter().zip(prefixes).enumerate() {
+            let (start, end) = match ghost {
+                0 => ("AAA".to_string(), "ZZZ".to_string()),
+                _ => (format!("{}A", prefix), format!("{}Z", prefix)),
+            };
+            let mut path = vec![start];
+            path.extend(names.by_ref().take(length - 1));
+            path.push(end);
+
+            for (i, id) in path.iter().enumerate() {
+                let next = &path[if i < length { i + 1 } else { 1 }];
+                let decoy = &path[rng.usize(..path.len())];
+                let (left, right) = match instructions[i % size] {
+                    'L' => (next, decoy),
+                    _ => (decoy, next),
+                };
+                nodes.push(format!("{} = ({}, {})", id, left, right));
+            }
+        }
+        rng.shuffle(&mut nodes);
+
+        Some(format!(
+            "{}\n\n{}",
+            String::from_iter(instructions),
+            nodes.join("\n")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+
+    sample_tests! {
+        Day;
+        b_sample: B, include_str!("sample.txt") => 6;
+        a_sample_rl: A, include_str!("sample_rl.txt") => 2;
+        b_sample_rl: B, include_str!("sample_rl.txt") => 2;
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{cancel, util::parse::Source};
+use anyhow::*;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Left,
+    Right,
+}
+impl TryFrom<&str> for Instruction {
+    type Error = anyhow::Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        match s {
+            "L" => Ok(Instruction::Left),
+            "R" => Ok(Instruction::Right),
+            _ => Err(Source(s).error(s, "`L` or `R`").into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Node<'a> {
+    pub id: &'a str,
+    pub left: &'a str,
+    pub right: &'a str,
+}
+
+impl<'a> TryFrom<&'a str> for Node<'a> {
+    type Error = anyhow::Error;
+
+    fn try_from(s: &'a str) -> Result<Self> {
+        let src = Source(s);
+        let (id, left_right) = src.split_once(s, " = ")?;
+        let left_right = src.strip_prefix(left_right, "(")?;
+        let left_right = src.strip_suffix(left_right, ")")?;
+        let (left, right) = src.split_once(left_right, ", ")?;
+
+        Ok(Node { id, left, right })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Network<'a> {
+    pub nodes: HashMap<&'a str, Node<'a>>,
+    pub instructions: Vec<Instruction>,
+}
+
+impl<'a> Network<'a> {
+    pub fn steps_to_reach<P>(&self, start: &'a str, predicate: P) -> Result<u64>
+    where
+        P: Fn(&str) -> bool,
+    {
+        let err = || anyhow!("Failed to find element");
+        let token = cancel::current();
+        let mut steps_taken = 0u32;
+        let mut current_node = start;
+
+        // Never ends if no node matches, so let the runner stop us
+        for instruction in self.instructions.iter().cycle() {
+            if steps_taken.is_multiple_of(1024) {
+                token.check()?;
+            }
+            let node = self.nodes.get(current_node).ok_or_else(err)?;
+            let next_node = match instruction {
+                Instruction::Left => node.left,
+                Instruction::Right => node.right,
+            };
+
+            steps_taken += 1;
+
+            if predicate(next_node) {
+                break;
+            }
+
+            current_node = next_node;
+        }
+
+        Ok(steps_taken.into())
+    }
+}
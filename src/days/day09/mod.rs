@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use crate::{util::parse::*, *};
+use anyhow::*;
+use fastrand::Rng;
+use itertools::Itertools;
+
+#[derive(Debug, Clone)]
+pub struct Sequence(Vec<i32>);
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Vec<Sequence>;
+    type Answer = i32;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 114;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 2;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(input.into_iter().map(|s| s.next_in_sequence()).sum())
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(input
+            .into_iter()
+            .map(|s| s.reverse())
+            .map(|s| s.next_in_sequence())
+            .sum())
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        parse_lines(data)
+    }
+
+    /// `size` sequences, each the values of a polynomial of degree five or less.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let sequence = |rng: &mut Rng| {
+            let coefficients = (0..=rng.usize(0..=5))
+                .map(|_| rng.i32(-5..=5))
+                .collect_vec();
+            let start = rng.i32(-10..=10);
+            (start..start + rng.i32(5..=21))
+                .map(|x| coefficients.iter().fold(0, |value, c| value * x + c))
+                .join(" ")
+        };
+        Some((0..size).map(|_| sequence(rng)).join("\n"))
+    }
+}
+
+impl FromStr for Sequence {
+    type Err = anyhow::Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let src = Source(line);
+        Ok(Self(
+            line.split_ascii_whitespace()
+                .map(|s| src.parse(s, "a number"))
+                .try_collect()?,
+        ))
+    }
+}
+
+impl Sequence {
+    fn differences(&self) -> Self {
+        Self(self.0.iter().tuple_windows().map(|(a, b)| b - a).collect())
+    }
+
+    fn reverse(self) -> Self {
+        Self(self.0.into_iter().rev().collect())
+    }
+
+    fn next_in_sequence(self) -> i32 {
+        let mut result = 0;
+        let mut current_sequence = self;
+        loop {
+            result += current_sequence.0.iter().last().unwrap();
+            if current_sequence.0.iter().all(|&n| n == 0) {
+                return result;
+            }
+            current_sequence = current_sequence.differences();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
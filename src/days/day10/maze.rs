@@ -1,5 +1,6 @@
 use std::{collections::HashMap, str::FromStr};
 
+use crate::util::parse::Source;
 use anyhow::*;
 use itertools::Itertools;
 
@@ -40,20 +41,21 @@ impl FromStr for Maze {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let src = Source(s);
         let mut start_position = None;
         let mut map = HashMap::new();
 
         for (y, line) in s.lines().enumerate() {
-            for (x, c) in line.chars().enumerate() {
+            for (x, c) in line.split_inclusive(|_: char| true).enumerate() {
                 let pos = Point {
                     x: x.try_into()?,
                     y: y.try_into()?,
                 };
-                if c == 'S' {
+                if c == "S" {
                     start_position = Some(pos.clone());
                     continue;
                 }
-                let pipe = Pipe::try_from(c)?;
+                let pipe = Pipe::try_from(c).map_err(|error| src.within(error, c))?;
                 map.insert(pos, pipe);
             }
         }
@@ -132,12 +134,32 @@ impl Maze {
 
         Some(shoelace + 1 - (polygon.len() / 2))
     }
+
+    /// Scans each row, flipping between outside and inside at every loop pipe that goes
+    /// north, instead of using the shoelace formula and Pick's theorem.
+    #[cfg(test)]
+    fn count_tiles_in_loop_naive(&self) -> Option<usize> {
+        let path: std::collections::HashSet<Point> = self.traverse_loop()?.into_iter().collect();
+        let mut inside = 0;
+        for y in 0..self.height {
+            let mut crossings = 0;
+            for x in 0..self.width {
+                let point = Point { x, y };
+                if !path.contains(&point) {
+                    inside += crossings % 2;
+                } else if self.get(&point)?.connects_to()?.contains(&Direction::North) {
+                    crossings += 1;
+                }
+            }
+        }
+        Some(inside)
+    }
 }
 
 fn shoelace_formula(loop_coords: &[Point]) -> usize {
     loop_coords
         .iter()
-        .tuple_windows()
+        .circular_tuple_windows()
         .map(|(p1, p2)| p1.x * p2.y - p2.x * p1.y)
         .sum::<isize>()
         .unsigned_abs()
@@ -211,19 +233,19 @@ impl Pipe {
     }
 }
 
-impl TryFrom<char> for Pipe {
+impl TryFrom<&str> for Pipe {
     type Error = anyhow::Error;
 
-    fn try_from(value: char) -> Result<Self, Self::Error> {
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         Ok(match value {
-            '|' => Self::NorthSouth,
-            '-' => Self::EastWest,
-            'F' => Self::SouthEast,
-            '7' => Self::SouthWest,
-            'L' => Self::NorthEast,
-            'J' => Self::NorthWest,
-            '.' => Self::Ground,
-            _ => Err(anyhow!("Invalid pipe type: {}", value))?,
+            "|" => Self::NorthSouth,
+            "-" => Self::EastWest,
+            "F" => Self::SouthEast,
+            "7" => Self::SouthWest,
+            "L" => Self::NorthEast,
+            "J" => Self::NorthWest,
+            "." => Self::Ground,
+            _ => return Err(Source(value).error(value, "a pipe, `.` or `S`").into()),
         })
     }
 }
@@ -244,3 +266,26 @@ impl Map {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days::day10::Day, BasicSolution};
+    use fastrand::Rng;
+
+    #[test]
+    fn pick_matches_scanning_rows() -> Result<()> {
+        for seed in 0..200 {
+            let input = Day::generate(&mut Rng::with_seed(seed), 8).unwrap();
+            let maze = Day::parse(&input)?;
+
+            assert_eq!(
+                maze.count_tiles_in_loop(),
+                maze.count_tiles_in_loop_naive(),
+                "\n{}",
+                input
+            );
+        }
+        Ok(())
+    }
+}
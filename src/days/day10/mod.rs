@@ -0,0 +1,171 @@
+use crate::{util::generate, *};
+use anyhow::*;
+use fastrand::Rng;
+use itertools::Itertools;
+pub use maze::Maze;
+use std::{cmp::Ordering, ops::Range};
+
+pub struct Day;
+
+pub mod maze;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Maze;
+    type Answer = usize;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_DATA_B: &'static str = include_str!("sample_b.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 8;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 10;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let err = || anyhow!("Failed to loop");
+        let path = input.traverse_loop().ok_or_else(err)?;
+
+        Ok(path.len() / 2)
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let err = || anyhow!("Failed to count ground");
+        let points = input.count_tiles_in_loop().ok_or_else(err)?;
+
+        Ok(points)
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        let mut data: Self::Parsed<'_> = data.parse()?;
+        data.find_start_type();
+        Ok(data)
+    }
+
+    /// A field of junk pipes about `size` by `size`, with one loop through the start and
+    /// nothing else connecting to it.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let (width, height) = (size.max(2), size.max(4));
+        let junk = [
+            ('.', 4),
+            ('|', 1),
+            ('-', 1),
+            ('L', 1),
+            ('J', 1),
+            ('7', 1),
+            ('F', 1),
+        ];
+        let mut grid = (0..height + 2)
+            .map(|_| {
+                (0..width + 2)
+                    .map(|_| generate::weighted(rng, &junk))
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        let path = random_loop(rng, width, height)
+            .into_iter()
+            .map(|(x, y)| (x + 1, y + 1))
+            .collect_vec();
+        for (i, &(x, y)) in path.iter().enumerate() {
+            let previous = path[(i + path.len() - 1) % path.len()];
+            let next = path[(i + 1) % path.len()];
+            grid[y][x] = pipe((x, y), [previous, next]);
+        }
+
+        let (x, y) = path[rng.usize(..path.len())];
+        grid[y][x] = 'S';
+        for neighbour in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+            if !path.contains(&neighbour) {
+                grid[neighbour.1][neighbour.0] = '.';
+            }
+        }
+
+        Some(grid.into_iter().map(String::from_iter).join("\n"))
+    }
+}
+
+/// The cells of a loop around the region between a wavy top edge and a wavy bottom edge,
+/// in order.
+fn random_loop(rng: &mut Rng, width: usize, height: usize) -> Vec<(usize, usize)> {
+    let mut wavy = |rows: Range<usize>| {
+        let mut edge = vec![rng.usize(rows.clone())];
+        for x in 1..width {
+            let wave = edge[x - 1] as isize + rng.isize(-2..=2);
+            edge.push(wave.clamp(rows.start as isize, rows.end as isize - 1) as usize);
+        }
+        edge
+    };
+    let mut top = wavy(0..height / 2);
+    let mut bottom = wavy(height / 2 + 1..height);
+    // Square off the corners where an edge would otherwise double back on itself
+    top[width - 1] = top[width - 2];
+    bottom[0] = bottom[1];
+
+    fn go(path: &mut Vec<(usize, usize)>, to: (usize, usize)) {
+        while let Some(&(x, y)) = path.last().filter(|&&last| last != to) {
+            let toward = |from: usize, to: usize| match from.cmp(&to) {
+                Ordering::Less => from + 1,
+                Ordering::Equal => from,
+                Ordering::Greater => from - 1,
+            };
+            path.push((toward(x, to.0), toward(y, to.1)));
+        }
+    }
+
+    let mut path = vec![(0, top[0])];
+    for x in 1..width {
+        go(&mut path, (x, top[x - 1]));
+        go(&mut path, (x, top[x]));
+    }
+    go(&mut path, (width - 1, bottom[width - 1]));
+    for x in (0..width - 1).rev() {
+        go(&mut path, (x, bottom[x + 1]));
+        go(&mut path, (x, bottom[x]));
+    }
+    go(&mut path, (0, top[0]));
+    path.pop();
+    path
+}
+
+/// The pipe at `cell` that connects the two cells next to it in `ends`.
+fn pipe((x, y): (usize, usize), ends: [(usize, usize); 2]) -> char {
+    let mut directions = ends.map(|end| match (end.0.cmp(&x), end.1.cmp(&y)) {
+        (Ordering::Equal, Ordering::Less) => 'N',
+        (Ordering::Equal, Ordering::Greater) => 'S',
+        (Ordering::Greater, _) => 'E',
+        _ => 'W',
+    });
+    directions.sort_unstable();
+    match directions {
+        ['N', 'S'] => '|',
+        ['E', 'W'] => '-',
+        ['E', 'N'] => 'L',
+        ['N', 'W'] => 'J',
+        ['S', 'W'] => '7',
+        ['E', 'S'] => 'F',
+        _ => unreachable!("a loop never doubles back"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+
+    sample_tests! {
+        Day;
+        b_sample: B, include_str!("sample.txt") => 1;
+        a_sample_square: A, include_str!("sample_square.txt") => 4;
+        b_sample_square: B, include_str!("sample_square.txt") => 1;
+        b_sample_enclosed: B, include_str!("sample_enclosed.txt") => 4;
+    }
+}
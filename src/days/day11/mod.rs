@@ -0,0 +1,66 @@
+use crate::{util::generate, *};
+use anyhow::*;
+use fastrand::Rng;
+
+pub struct Day;
+
+pub mod universe;
+use itertools::Itertools;
+pub use universe::*;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Universe;
+    type Answer = u64;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 374;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 82000210;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(input
+            .galaxies
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| input.distance(a, b, 2))
+            .sum())
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(input
+            .galaxies
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| input.distance(a, b, 1_000_000))
+            .sum())
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        data.parse()
+    }
+
+    /// A `size` by `size` image with a galaxy in about one in ten places.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let pixels = [('#', 1), ('.', 9)];
+        Some(generate::grid(rng, size, size, |rng| {
+            generate::weighted(rng, &pixels)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{util::parse::parse_lines, *};
+use anyhow::*;
+use fastrand::Rng;
+use itertools::Itertools;
+pub use springs::SpringFormation;
+
+pub mod springs;
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Vec<SpringFormation>;
+    type Answer = usize;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 21;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 525152;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(input
+            .into_iter()
+            .map(|formation| formation.count_arrangements())
+            .sum())
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(input
+            .into_iter()
+            .map(|formation| formation.unfold())
+            .map(|formation| formation.count_arrangements())
+            .sum())
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        parse_lines(data)
+    }
+
+    /// `size` rows of up to 38 springs, about half of them unknown.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let row = |rng: &mut Rng| {
+            let groups = (0..rng.usize(1..=5))
+                .map(|_| rng.usize(1..=4))
+                .collect_vec();
+            let mut springs = ".".repeat(rng.usize(0..=3));
+            for &group in &groups {
+                springs += &"#".repeat(group);
+                springs += &".".repeat(rng.usize(1..=3));
+            }
+            let springs: String = springs
+                .chars()
+                .map(|spring| if rng.bool() { '?' } else { spring })
+                .collect();
+            format!("{} {}", springs, groups.iter().join(","))
+        };
+        Some((0..size).map(|_| row(rng)).join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::util::parse::Source;
 use anyhow::*;
 use itertools::Itertools;
 use std::{collections::HashMap, iter::once, str::FromStr};
@@ -10,15 +11,15 @@ pub enum SpringCondition {
 }
 use SpringCondition::*;
 
-impl TryFrom<char> for SpringCondition {
+impl TryFrom<&str> for SpringCondition {
     type Error = anyhow::Error;
 
-    fn try_from(value: char) -> Result<Self, Self::Error> {
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value {
-            '.' => Ok(Self::Operational),
-            '#' => Ok(Self::Broken),
-            '?' => Ok(Self::Unknown),
-            _ => Err(anyhow!("Invalid spring condition: {}", value)),
+            "." => Ok(Self::Operational),
+            "#" => Ok(Self::Broken),
+            "?" => Ok(Self::Unknown),
+            _ => Err(Source(value).error(value, "one of `.#?`").into()),
         }
     }
 }
@@ -38,6 +39,37 @@ impl SpringFormation {
         count_arrangements_recursive(&self.condition_sequence, &self.damaged_groups, &mut cache)
     }
 
+    /// Tries all 2^n ways to fill in the unknown springs, with no memoizing.
+    #[cfg(test)]
+    fn count_arrangements_naive(&self) -> usize {
+        let unknowns = self.condition_sequence.iter().filter(|&&c| c == Unknown);
+        (0..1usize << unknowns.count())
+            .filter(|&mask| {
+                let mut bit = 0;
+                let filled = self
+                    .condition_sequence
+                    .iter()
+                    .map(|&condition| match condition {
+                        Unknown => {
+                            bit += 1;
+                            if mask >> (bit - 1) & 1 == 1 {
+                                Broken
+                            } else {
+                                Operational
+                            }
+                        }
+                        known => known,
+                    })
+                    .collect_vec();
+                let groups = filled
+                    .chunk_by(|a, b| a == b)
+                    .filter(|run| run[0] == Broken)
+                    .map(<[_]>::len);
+                groups.eq(self.damaged_groups.iter().copied())
+            })
+            .count()
+    }
+
     pub fn unfold(&self) -> Self {
         let new_condition_sequence = self
             .condition_sequence
@@ -67,12 +99,15 @@ impl FromStr for SpringFormation {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let err = || anyhow!("Invalid spring formation: {}", s);
+        let src = Source(s);
+        let (sequence, groups) = src.split_once(s, " ")?;
 
-        let (sequence, groups) = s.split_once(' ').ok_or_else(err)?;
-
-        let condition_sequence = sequence.chars().map(|c| c.try_into()).try_collect()?;
-        let damaged_groups = groups.split(',').map(|s| s.parse()).try_collect()?;
+        let conditions = sequence.split_inclusive(|_: char| true);
+        let condition_sequence = src.parse_each(conditions, SpringCondition::try_from)?;
+        let damaged_groups = groups
+            .split(',')
+            .map(|group| src.parse(group, "a group size"))
+            .try_collect()?;
 
         Ok(Self {
             condition_sequence,
@@ -139,3 +174,30 @@ fn count_arrangements_recursive(
     cache.insert(cache_key, arrangements);
     arrangements
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fastrand::Rng;
+
+    #[test]
+    fn count_arrangements_matches_brute_force() -> Result<()> {
+        let mut rng = Rng::with_seed(12);
+        for _ in 0..500 {
+            let springs: String = (0..rng.usize(1..=12))
+                .map(|_| b".#?"[rng.usize(..3)] as char)
+                .collect();
+            let groups = (0..rng.usize(1..=3)).map(|_| rng.usize(1..=3)).join(",");
+            let formation: SpringFormation = format!("{} {}", springs, groups).parse()?;
+
+            assert_eq!(
+                formation.clone().count_arrangements(),
+                formation.count_arrangements_naive(),
+                "{} {}",
+                springs,
+                groups
+            );
+        }
+        Ok(())
+    }
+}
@@ -1,16 +1,20 @@
 use std::str::FromStr;
 
 use anyhow::*;
-use itertools::Itertools;
+
+use crate::util::grid::Grid;
 
 #[derive(Debug, Clone)]
-pub struct Field(Vec<Vec<char>>);
+pub struct Field(Grid<u8>);
 
 impl FromStr for Field {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        Ok(Self(s.lines().map(|line| line.chars().collect()).collect()))
+        Ok(Self(Grid::parse_with(s, |c| match c {
+            b'#' | b'.' => Ok(c),
+            _ => bail!("`#` or `.`"),
+        })?))
     }
 }
 
@@ -19,7 +23,7 @@ impl Field {
         if let Some(row) = self.find_reflection::<ALLOWANCE>() {
             return Some(row * 100);
         }
-        let r = self.rotate();
+        let r = self.transpose();
         if let Some(col) = r.find_reflection::<ALLOWANCE>() {
             return Some(col);
         }
@@ -29,33 +33,25 @@ impl Field {
         None
     }
     pub fn find_reflection<const ALLOWNACE: usize>(&self) -> Option<usize> {
-        (1..self.0.len()).find(|&i| {
-            let first_i_lines = &self.0[..i];
-            let last_i_lines = &self.0[i..];
-
-            let pairs = first_i_lines
-                .into_iter()
-                .rev()
-                .zip(last_i_lines.into_iter());
+        let height = self.0.height;
+        (1..height).find_map(|i| {
+            let pairs = (0..i).rev().zip(i..height);
 
             let diff = pairs
                 .map(|(a, b)| {
-                    a.into_iter()
-                        .zip(b.into_iter())
+                    self.0
+                        .row(a)
+                        .zip(self.0.row(b))
                         .filter(|(a, b)| a != b)
                         .count()
                 })
                 .sum::<usize>();
 
-            diff == ALLOWNACE
+            (diff == ALLOWNACE).then_some(i as usize)
         })
     }
 
-    pub fn rotate(&self) -> Self {
-        Self(
-            (0..self.0[0].len())
-                .map(|i| self.0.iter().map(|row| row[i]).collect_vec())
-                .collect_vec(),
-        )
+    pub fn transpose(&self) -> Self {
+        Self(self.0.transpose())
     }
 }
@@ -0,0 +1,132 @@
+use crate::{util::parse::Source, *};
+use anyhow::*;
+use fastrand::Rng;
+pub use field::Field;
+use itertools::Itertools;
+
+pub mod field;
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Vec<Field>;
+    type Answer = usize;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 405;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 400;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(input
+            .into_iter()
+            .map(|field| field.find_all_reflections::<0>().unwrap())
+            .sum())
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(input
+            .into_iter()
+            .map(|field| field.find_all_reflections::<1>().unwrap())
+            .sum())
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        Source(data).parse_each(data.split("\n\n"), str::parse)
+    }
+
+    /// `size` patterns, each with exactly one line of reflection and exactly one other line
+    /// that reflects all but a single smudge.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let patterns =
+            (0..size).map(|_| pattern(rng).into_iter().map(String::from_iter).join("\n"));
+        Some(patterns.collect_vec().join("\n\n"))
+    }
+}
+
+/// A pattern that reflects exactly across one row, and across one column but for a smudge,
+/// transposed half of the time.
+fn pattern(rng: &mut Rng) -> Vec<Vec<char>> {
+    loop {
+        let (width, height) = (rng.usize(5..=15), rng.usize(5..=15));
+        let (row, column) = (rng.usize(1..height), rng.usize(1..width));
+        let base = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| if rng.bool() { '#' } else { '.' })
+                    .collect_vec()
+            })
+            .collect_vec();
+        let fold = |i, mirror, len| reflected(i, mirror, len).map_or(i, |j| j.min(i));
+        let mut pattern = (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| base[fold(y, row, height)][fold(x, column, width)])
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        // The smudge must be out of the row mirror's reach, to keep that one exact
+        let ys = (0..height)
+            .filter(|&y| reflected(y, row, height).is_none())
+            .collect_vec();
+        let xs = (0..width)
+            .filter(|&x| reflected(x, column, width).is_some())
+            .collect_vec();
+        let (Some(&y), Some(&x)) = (rng.choice(&ys), rng.choice(&xs)) else {
+            continue;
+        };
+        pattern[y][x] = if pattern[y][x] == '#' { '.' } else { '#' };
+
+        let mut differences = reflection_differences(&pattern);
+        differences.extend(reflection_differences(&transpose(&pattern)));
+        let lines_differing_by = |n| differences.iter().filter(|&&d| d == n).count();
+        if lines_differing_by(0) == 1 && lines_differing_by(1) == 1 {
+            return if rng.bool() {
+                transpose(&pattern)
+            } else {
+                pattern
+            };
+        }
+    }
+}
+
+/// Where index `i` lands when mirrored between `mirror - 1` and `mirror`, if it's in range.
+fn reflected(i: usize, mirror: usize, len: usize) -> Option<usize> {
+    (2 * mirror).checked_sub(i + 1).filter(|&j| j < len)
+}
+
+/// How many cells differ across each line between rows, from the top.
+fn reflection_differences(rows: &[Vec<char>]) -> Vec<usize> {
+    (1..rows.len())
+        .map(|line| {
+            let pairs = rows[..line].iter().rev().zip(&rows[line..]);
+            pairs
+                .map(|(a, b)| a.iter().zip(b).filter(|(a, b)| a != b).count())
+                .sum()
+        })
+        .collect()
+}
+
+fn transpose(rows: &[Vec<char>]) -> Vec<Vec<char>> {
+    (0..rows[0].len())
+        .map(|x| rows.iter().map(|row| row[x]).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{util::generate, *};
+use anyhow::*;
+use fastrand::Rng;
+pub use rocks::{Platform, NORTH};
+
+pub mod rocks;
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Platform;
+    type Answer = usize;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 136;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 64;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let result = input.fully_tilt(NORTH);
+        Ok(result.calculate_load())
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let result = input.fully_rotate_tilt();
+        Ok(result.calculate_load())
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        data.parse()
+    }
+
+    /// A platform up to `size` rocks wide and high.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let (width, height) = (rng.usize(1..=size), rng.usize(1..=size));
+        let tiles = [('#', 2), ('O', 3), ('.', 5)];
+        Some(generate::grid(rng, width, height, |rng| {
+            generate::weighted(rng, &tiles)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
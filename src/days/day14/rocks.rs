@@ -0,0 +1,157 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use crate::util::{
+    grid::{Grid, Render},
+    point::*,
+};
+
+pub const NORTH: Point = UP;
+pub const SOUTH: Point = DOWN;
+pub const EAST: Point = RIGHT;
+pub const WEST: Point = LEFT;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RockKind {
+    Round,
+    Square,
+}
+use RockKind::*;
+
+impl Render for Option<RockKind> {
+    fn render(&self) -> char {
+        match self {
+            Some(Round) => 'O',
+            Some(Square) => '#',
+            None => '.',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    pub rocks: Grid<Option<RockKind>>,
+}
+
+impl Platform {
+    pub fn calculate_load(&self) -> usize {
+        let round_rocks = self.rocks.iter().filter(|(_, kind)| **kind == Some(Round));
+        let loads = round_rocks.map(|(point, _)| (self.rocks.height - point.y) as usize);
+
+        loads.sum()
+    }
+    pub fn fully_rotate_tilt(&self) -> Self {
+        let mut past_results = HashMap::new();
+        let mut past_results_index = Vec::new();
+        let mut current = self.clone();
+        let mut i = 0;
+        let (start, end) = loop {
+            if let Some((start, _)) = past_results.get(&current) {
+                break (*start, i);
+            }
+
+            let next = current.rotate_tilt();
+            past_results.insert(current.clone(), (i, next.clone()));
+            past_results_index.push(current);
+            current = next;
+            i += 1;
+        };
+
+        let loop_length = end - start;
+        let offset = 1_000_000_000 - start;
+        let index = offset % loop_length + start;
+
+        past_results_index[index].clone()
+    }
+    /// Returns a new platform after a single full rotation cycle
+    pub fn rotate_tilt(&self) -> Self {
+        self.fully_tilt(NORTH)
+            .fully_tilt(WEST)
+            .fully_tilt(SOUTH)
+            .fully_tilt(EAST)
+    }
+    /// Tilts the platform towards `direction` by turning that side north, tilting north, and
+    /// turning it back.
+    pub fn fully_tilt(&self, direction: Point) -> Self {
+        let rocks = match direction {
+            NORTH => self.rocks.clone(),
+            SOUTH => self.rocks.flip_v(),
+            EAST => self.rocks.rotate_ccw(),
+            WEST => self.rocks.rotate_cw(),
+            _ => panic!("Can't tilt towards {:?}", direction),
+        };
+        let mut current = Self { rocks };
+        let mut result = current.tilt_north();
+        while result != current {
+            current = result;
+            result = current.tilt_north();
+        }
+
+        let rocks = match direction {
+            SOUTH => result.rocks.flip_v(),
+            EAST => result.rocks.rotate_cw(),
+            WEST => result.rocks.rotate_ccw(),
+            _ => result.rocks,
+        };
+        Self { rocks }
+    }
+    fn tilt_north(&self) -> Self {
+        let mut rocks = self.rocks.clone();
+
+        for (point, kind) in self.rocks.iter() {
+            let above = point + NORTH;
+            if *kind == Some(Round) && self.rocks.get(above) == Some(&None) {
+                rocks[above] = Some(Round);
+                rocks[point] = None;
+            }
+        }
+
+        Self { rocks }
+    }
+}
+
+impl FromStr for Platform {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rocks = Grid::parse_with(s, |c| match c {
+            b'O' => Ok(Some(Round)),
+            b'#' => Ok(Some(Square)),
+            _ => Ok(None),
+        })?;
+
+        Ok(Self { rocks })
+    }
+}
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.rocks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn platform_hash_works() -> anyhow::Result<()> {
+        let platform1: Platform = "O#\n.O\n".parse()?;
+        let platform2: Platform = "O#\n.O\n".parse()?;
+
+        let mut map = HashMap::new();
+
+        map.insert(platform1, 1);
+        assert_eq!(map.get(&platform2), Some(&1));
+        Ok(())
+    }
+
+    #[test]
+    fn tilts_every_way() -> anyhow::Result<()> {
+        let platform: Platform = "O.#\n..O\n.O.\n".parse()?;
+        assert_eq!(platform.fully_tilt(NORTH).to_string(), "OO#\n..O\n...\n");
+        assert_eq!(platform.fully_tilt(SOUTH).to_string(), "..#\n...\nOOO\n");
+        assert_eq!(platform.fully_tilt(EAST).to_string(), ".O#\n..O\n..O\n");
+        assert_eq!(platform.fully_tilt(WEST).to_string(), "O.#\nO..\nO..\n");
+        Ok(())
+    }
+}
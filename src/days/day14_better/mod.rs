@@ -0,0 +1,54 @@
+use crate::*;
+use anyhow::*;
+use fastrand::Rng;
+pub use rocks::Grid;
+
+pub mod rocks;
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Grid;
+    type Answer = usize;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 136;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 64;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(mut input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        input.tilt_north();
+        Ok(input.score())
+    }
+
+    fn part2(mut input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let final_grid = input.tilt_cycle::<1_000_000_000>();
+        Ok(final_grid.score())
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        data.parse()
+    }
+
+    /// The same platforms day 14 makes.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        <super::day14::Day as BasicSolution>::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
@@ -0,0 +1,119 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use anyhow::*;
+use itertools::Itertools;
+
+use crate::{span, util::grid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rock {
+    Round,
+    Square,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    rocks: grid::Grid<Option<Rock>>,
+}
+
+impl Grid {
+    pub fn width(&self) -> i32 {
+        self.rocks.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.rocks.height
+    }
+
+    pub fn score(&self) -> usize {
+        self.rocks
+            .iter()
+            .filter(|(_, rock)| **rock == Some(Rock::Round))
+            .map(|(point, _)| (self.height() - point.y) as usize)
+            .sum()
+    }
+
+    pub fn tilt_cycle<const CYCLES: usize>(&mut self) -> Self {
+        let _span = span::enter("find cycle");
+        let mut cache: Vec<Grid> = Vec::new();
+        let (start, end) = loop {
+            if let Some((index, _)) = cache.iter().find_position(|p| *p == self) {
+                break (index, cache.len());
+            }
+            let current = self.clone();
+            cache.push(current);
+            self.tilt_rotate();
+        };
+
+        let loop_length = end - start;
+        let offset = CYCLES - start;
+        let index = offset % loop_length + start;
+
+        cache[index].clone()
+    }
+
+    fn tilt_rotate(&mut self) {
+        let _span = span::enter("spin");
+        self.tilt_north();
+        self.tilt_west();
+        self.tilt_south();
+        self.tilt_east();
+    }
+
+    pub fn tilt_north(&mut self) {
+        for x in 0..self.width() {
+            tilt(self.rocks.column_mut(x));
+        }
+    }
+
+    fn tilt_south(&mut self) {
+        for x in 0..self.width() {
+            tilt(self.rocks.column_mut(x).rev());
+        }
+    }
+
+    fn tilt_east(&mut self) {
+        for y in 0..self.height() {
+            tilt(self.rocks.row_mut(y).rev());
+        }
+    }
+
+    fn tilt_west(&mut self) {
+        for y in 0..self.height() {
+            tilt(self.rocks.row_mut(y));
+        }
+    }
+}
+
+/// Rolls every round rock in `line` towards its start, until it hits a rock or the edge.
+fn tilt<'a>(line: impl Iterator<Item = &'a mut Option<Rock>>) {
+    let mut empty = VecDeque::new();
+    for tile in line {
+        match tile {
+            None => empty.push_back(tile),
+            Some(Rock::Square) => empty.clear(),
+            Some(Rock::Round) => {
+                if let Some(free) = empty.pop_front() {
+                    *free = Some(Rock::Round);
+                    *tile = None;
+                    empty.push_back(tile);
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Grid {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rocks = grid::Grid::parse_with(s, |c| match c {
+            b'O' => Ok(Some(Rock::Round)),
+            b'#' => Ok(Some(Rock::Square)),
+            b'.' => Ok(None),
+            _ => bail!("`O`, `#` or `.`"),
+        })?;
+
+        Ok(Self { rocks })
+    }
+}
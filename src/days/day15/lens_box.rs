@@ -1,11 +1,12 @@
+use crate::util::parse::Source;
 use anyhow::*;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct LensBox {
-    boxes: Vec<Vec<Lens>>,
+pub struct LensBox<'a> {
+    boxes: Vec<Vec<Lens<'a>>>,
 }
 
-impl LensBox {
+impl<'a> LensBox<'a> {
     pub fn new() -> Self {
         Self::default()
     }
@@ -24,7 +25,7 @@ impl LensBox {
             .sum()
     }
 
-    pub fn run_instructions(&mut self, instructions: &[Instruction]) {
+    pub fn run_instructions(&mut self, instructions: &[Instruction<'a>]) {
         for instruction in instructions {
             match instruction {
                 Instruction::Insert(address, lens) => {
@@ -37,7 +38,7 @@ impl LensBox {
         }
     }
 
-    fn insert(&mut self, address: u8, lens: Lens) {
+    fn insert(&mut self, address: u8, lens: Lens<'a>) {
         let box_index = address as usize;
         let position = self.boxes[box_index]
             .iter()
@@ -53,7 +54,7 @@ impl LensBox {
         }
     }
 
-    fn remove(&mut self, address: u8, label: &'static str) {
+    fn remove(&mut self, address: u8, label: &'a str) {
         let box_index = address as usize;
         let position = self.boxes[box_index].iter().position(|l| l.label == label);
 
@@ -63,7 +64,7 @@ impl LensBox {
     }
 }
 
-impl Default for LensBox {
+impl Default for LensBox<'_> {
     fn default() -> Self {
         Self {
             boxes: vec![vec![]; 256],
@@ -72,32 +73,32 @@ impl Default for LensBox {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Lens {
+pub struct Lens<'a> {
     value: u8,
-    label: &'static str,
+    label: &'a str,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Instruction {
-    Insert(u8, Lens),
-    Remove(u8, &'static str),
+pub enum Instruction<'a> {
+    Insert(u8, Lens<'a>),
+    Remove(u8, &'a str),
 }
 
-impl TryFrom<&'static str> for Instruction {
+impl<'a> TryFrom<&'a str> for Instruction<'a> {
     type Error = anyhow::Error;
 
-    fn try_from(s: &'static str) -> Result<Self, Self::Error> {
-        let err = || anyhow!("Invalid instruction: {}", s);
-        let is_insert = s.contains('=');
-        Ok(if is_insert {
-            let (label, value) = s.split_once('=').ok_or_else(err)?;
-            let address = hash(label);
-            let value = value.parse().map_err(|_| err())?;
-            Instruction::Insert(address, Lens { value, label })
-        } else {
-            let label = s[..s.len() - 1].trim();
-            let address = hash(label);
-            Instruction::Remove(address, label)
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let src = Source(s);
+        Ok(match s.split_once('=') {
+            Some((label, value)) => {
+                let value = src.parse(value, "a focal length")?;
+                Instruction::Insert(hash(label), Lens { value, label })
+            }
+            None => {
+                let err = || src.error(s, "a step like `rn=1` or `cm-`");
+                let label = s.strip_suffix('-').ok_or_else(err)?;
+                Instruction::Remove(hash(label), label)
+            }
         })
     }
 }
@@ -0,0 +1,83 @@
+use crate::{util::parse::Source, *};
+use anyhow::*;
+use fastrand::Rng;
+
+pub struct Day;
+
+pub mod lens_box;
+use itertools::Itertools;
+pub use lens_box::*;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Vec<(&'a str, Instruction<'a>)>;
+    type Answer = usize;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 1320;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 145;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(input.iter().map(|(step, _)| hash(step) as usize).sum())
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let mut lens_box = LensBox::new();
+        let instructions = input.into_iter().map(|(_, instruction)| instruction);
+        lens_box.run_instructions(&instructions.collect_vec());
+
+        Ok(lens_box.focus_power())
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        Source(data).parse_each(data.trim_end().split(','), |step| {
+            Ok((step, Instruction::try_from(step)?))
+        })
+    }
+
+    /// `size` steps that put lenses in or take them out, with labels from a small pool so
+    /// they meet again.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let labels = (0..size.div_ceil(3))
+            .map(|_| {
+                let len = rng.usize(2..=6);
+                (0..len).map(|_| rng.lowercase()).collect::<String>()
+            })
+            .collect_vec();
+        let steps = (0..size).map(|_| {
+            let label = &labels[rng.usize(..labels.len())];
+            match rng.usize(0..3) {
+                0 => format!("{}-", label),
+                _ => format!("{}={}", label, rng.u8(1..=9)),
+            }
+        });
+        Some(steps.collect_vec().join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::parse::ParseError;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+
+    #[test]
+    fn bad_steps_are_parse_errors() {
+        for (data, column) in [("rn=1,", 6), ("rn=1,cm", 6), ("rn=x", 4)] {
+            let error = <Day as BasicSolution>::parse(data).unwrap_err();
+            let error = error.downcast::<ParseError>().unwrap();
+            assert_eq!((error.line, error.column), (1, column), "{}", data);
+        }
+    }
+}
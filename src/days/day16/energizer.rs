@@ -1,10 +1,11 @@
 use anyhow::*;
-use itertools::Itertools;
 use std::{
     collections::{HashSet, VecDeque},
     str::FromStr,
 };
 
+use crate::util::{grid::Grid, point::Point};
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Tile {
     EmptySpace,
@@ -17,28 +18,21 @@ use Tile::*;
 
 #[derive(Debug, Clone)]
 pub struct Energizer {
-    pub grid: Vec<Vec<Tile>>,
+    pub grid: Grid<Tile>,
 }
 
 impl FromStr for Energizer {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let grid = s
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|c| match c {
-                        '.' => Ok(EmptySpace),
-                        '-' => Ok(LeftRightSplitter),
-                        '|' => Ok(UpDownSplitter),
-                        '/' => Ok(RightLeftMirror),
-                        '\\' => Ok(LeftRightMirror),
-                        _ => Err(anyhow!("Invalid character: {}", c)),
-                    })
-                    .try_collect()
-            })
-            .try_collect()?;
+        let grid = Grid::parse_with(s, |c| match c {
+            b'.' => Ok(EmptySpace),
+            b'-' => Ok(LeftRightSplitter),
+            b'|' => Ok(UpDownSplitter),
+            b'/' => Ok(RightLeftMirror),
+            b'\\' => Ok(LeftRightMirror),
+            _ => bail!("a mirror, splitter or `.`"),
+        })?;
 
         Ok(Self { grid })
     }
@@ -48,6 +42,19 @@ impl Energizer {
     pub fn iter(&self, first_ray: Ray) -> EnergizerIter {
         EnergizerIter::new(self, first_ray)
     }
+
+    pub fn width(&self) -> usize {
+        self.grid.width as usize
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.height as usize
+    }
+
+    fn tile(&self, x: usize, y: usize) -> Option<&Tile> {
+        self.grid
+            .get(Point::new(x.try_into().ok()?, y.try_into().ok()?))
+    }
 }
 
 pub struct EnergizerIter<'a> {
@@ -74,11 +81,7 @@ impl Iterator for EnergizerIter<'_> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let ray = self.rays.pop_front()?;
-        let tile = self
-            .energizer
-            .grid
-            .get(ray.y)
-            .and_then(|row| row.get(ray.x));
+        let tile = self.energizer.tile(ray.x, ray.y);
 
         if let Some(_) = self.visited.get(&ray) {
             return self.next();
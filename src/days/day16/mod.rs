@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use crate::{util::generate, *};
+use anyhow::*;
+pub use energizer::{Energizer, Ray};
+use fastrand::Rng;
+use rayon::prelude::*;
+pub mod energizer;
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Energizer;
+    type Answer = usize;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 46;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 51;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(count_visited_tiles(&input, Ray::new(0, 0, 1, 0)))
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let err = || anyhow!("No solution found");
+
+        let (width, height) = (input.width(), input.height());
+        let southward_rays = (0..width).map(|x| Ray::new(x, 0, 0, 1));
+        let northward_rays = (0..width).map(|x| Ray::new(x, height - 1, 0, -1));
+        let eastward_rays = (0..height).map(|y| Ray::new(0, y, 1, 0));
+        let westward_rays = (0..height).map(|y| Ray::new(width - 1, y, -1, 0));
+
+        let all_rays = southward_rays
+            .chain(northward_rays)
+            .chain(eastward_rays)
+            .chain(westward_rays);
+
+        all_rays
+            // Look ma, parallelism!
+            .par_bridge()
+            .map(|ray| count_visited_tiles(&input, ray))
+            .max()
+            .ok_or_else(err)
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        data.parse()
+    }
+
+    /// A `size` by `size` contraption with a mirror or splitter in about one in five places.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let tiles = [('.', 16), ('/', 1), ('\\', 1), ('-', 1), ('|', 1)];
+        Some(generate::grid(rng, size, size, |rng| {
+            generate::weighted(rng, &tiles)
+        }))
+    }
+}
+
+fn count_visited_tiles(energizer: &Energizer, first_ray: Ray) -> usize {
+    energizer.iter(first_ray).collect::<HashSet<_>>().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
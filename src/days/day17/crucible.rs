@@ -1,30 +1,21 @@
 use anyhow::*;
-use itertools::Itertools;
-use std::{
-    collections::{BinaryHeap, HashSet},
-    ops::Sub,
-    str::FromStr,
-};
+use std::{ops::Sub, str::FromStr};
+
+use crate::util::{grid::Grid, point::Point, search};
 
 #[derive(Debug, Clone)]
 pub struct Field {
-    pub cost_map: Vec<Vec<u8>>,
+    pub cost_map: Grid<u8>,
 }
 
 impl FromStr for Field {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let err = || anyhow!("Invalid field");
-
-        let cost_map = s
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|c| c.to_digit(10).map(|d| d as u8).ok_or_else(err))
-                    .try_collect()
-            })
-            .try_collect()?;
+        let cost_map = Grid::parse_with(s, |c| {
+            let digit = (c as char).to_digit(10).map(|d| d as u8);
+            digit.ok_or_else(|| anyhow!("a heat loss digit"))
+        })?;
 
         Ok(Self { cost_map })
     }
@@ -34,41 +25,21 @@ impl Field {
     pub fn find_best_lava_path<const MIN_STEPS: usize, const MAX_STEPS: usize>(
         &self,
     ) -> Option<usize> {
-        let start = Vector2D(0, 0);
+        let start = LavaFlowStep {
+            point: Vector2D(0, 0),
+            direction: Vector2D(0, 0),
+            in_a_row: 0,
+        };
         let end = Vector2D(
-            self.cost_map.len() as isize - 1,
-            self.cost_map[0].len() as isize - 1,
+            self.cost_map.width as isize - 1,
+            self.cost_map.height as isize - 1,
         );
-        let mut frontier = BinaryHeap::new();
-        let mut visited = HashSet::new();
-
-        frontier.push(LavaFlowStepCost(
-            0,
-            LavaFlowStep {
-                point: start,
-                direction: Vector2D(0, 0),
-                in_a_row: 0,
-            },
-        ));
-
-        while let Some(LavaFlowStepCost(step_cost, step)) = frontier.pop() {
+
+        let successors = |step: &LavaFlowStep| {
             let LavaFlowStep {
                 point, in_a_row, ..
-            } = step;
-
-            if !visited.insert(step) {
-                continue;
-            }
-
-            if in_a_row >= MAX_STEPS {
-                // This step is invalid, it goes over the maximum
-                continue;
-            }
-
-            if point == end && in_a_row >= MIN_STEPS {
-                // We reached the end, and we made enough steps to reach it
-                return Some(step_cost);
-            }
+            } = *step;
+            let mut next_steps = Vec::with_capacity(4);
 
             for next in self.adjacent(&point) {
                 let next_cost = match self.get(&next) {
@@ -90,22 +61,29 @@ impl Field {
                     continue; // don't go back
                 }
 
+                let next_in_a_row = if going_straight { in_a_row + 1 } else { 0 };
+                if next_in_a_row >= MAX_STEPS {
+                    continue; // This step is invalid, it goes over the maximum
+                }
+
                 let next_step = LavaFlowStep {
                     point: next,
                     direction: next_direction,
-                    in_a_row: if going_straight { in_a_row + 1 } else { 0 },
+                    in_a_row: next_in_a_row,
                 };
-                frontier.push(LavaFlowStepCost(step_cost + next_cost, next_step));
+                next_steps.push((next_step, next_cost));
             }
-        }
+            next_steps
+        };
+        // We reached the end, and we made enough steps to reach it
+        let is_end = |step: &LavaFlowStep| step.point == end && step.in_a_row >= MIN_STEPS;
 
-        None
+        search::dijkstra(start, successors, is_end).map(|found| found.cost)
     }
 
     pub fn get(&self, point: &Vector2D) -> Option<usize> {
-        let (x, y) = point.get()?;
-
-        self.cost_map.get(y)?.get(x).map(|&c| c.into())
+        let (x, y) = (point.0.try_into().ok()?, point.1.try_into().ok()?);
+        self.cost_map.get(Point::new(x, y)).map(|&cost| cost.into())
     }
 
     pub fn adjacent(&self, point: &Vector2D) -> Vec<Vector2D> {
@@ -122,13 +100,6 @@ impl Field {
 pub struct Vector2D(pub isize, pub isize);
 
 impl Vector2D {
-    pub fn get(&self) -> Option<(usize, usize)> {
-        if self.0.is_negative() || self.1.is_negative() {
-            return None;
-        }
-        Some((self.0 as usize, self.1 as usize))
-    }
-
     pub fn reverse(&self) -> Vector2D {
         Vector2D(-self.0, -self.1)
     }
@@ -156,18 +127,3 @@ pub struct LavaFlowStep {
     direction: Vector2D,
     in_a_row: usize,
 }
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct LavaFlowStepCost(usize, LavaFlowStep);
-
-impl PartialOrd for LavaFlowStepCost {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.0.partial_cmp(&other.0).map(|o| o.reverse())
-    }
-}
-
-impl Ord for LavaFlowStepCost {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.0.cmp(&other.0).reverse()
-    }
-}
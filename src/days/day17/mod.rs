@@ -0,0 +1,58 @@
+use crate::{util::generate, *};
+use anyhow::*;
+pub use crucible::*;
+use fastrand::Rng;
+
+pub mod crucible;
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Field;
+    type Answer = usize;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 102;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 94;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        input
+            .find_best_lava_path::<0, 3>()
+            .ok_or_else(|| anyhow!("No path found"))
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        input
+            .find_best_lava_path::<4, 10>()
+            .ok_or_else(|| anyhow!("No path found"))
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        data.parse()
+    }
+
+    /// A `size` by `size` map of heat losses, at least 6 wide so the ultra crucible can
+    /// reach the end.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(6);
+        Some(generate::grid(rng, size, size, |rng| rng.char('1'..='9')))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
@@ -1,14 +1,15 @@
+use crate::util::parse::Source;
 use anyhow::*;
 use itertools::Itertools;
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct Lagoon {
     map: Vec<(isize, isize)>,
 }
 
 impl Lagoon {
     pub fn new() -> Self {
-        Self { map: Vec::new() }
+        Self::default()
     }
     pub fn dig_trench(&mut self, instructions: Vec<Instruction>) -> &mut Self {
         let mut x: isize = 0;
@@ -47,18 +48,21 @@ impl Lagoon {
     }
 }
 
-impl TryFrom<&'static str> for Instruction {
+impl<'a> TryFrom<&'a str> for Instruction<'a> {
     type Error = anyhow::Error;
 
-    fn try_from(s: &'static str) -> Result<Self, Self::Error> {
-        let err = || anyhow!("Invalid input");
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let src = Source(s);
+        let [direction, amount, color] = src.fields(
+            s,
+            s.split_ascii_whitespace(),
+            "a direction, distance and color",
+        )?;
 
-        let (direction, amount, color) =
-            s.split_ascii_whitespace().collect_tuple().ok_or_else(err)?;
+        let amount = src.parse::<usize>(amount, "a distance")?;
 
-        let amount = amount.parse::<usize>()?;
-
-        let color = &color[2..color.len() - 1];
+        let color = src.strip_prefix(color, "(#")?;
+        let color = src.strip_suffix(color, ")")?;
 
         Ok(Instruction {
             direction,
@@ -69,14 +73,14 @@ impl TryFrom<&'static str> for Instruction {
 }
 
 #[derive(Debug, Clone)]
-pub struct Instruction {
-    direction: &'static str,
+pub struct Instruction<'a> {
+    direction: &'a str,
     distance: usize,
-    color: &'static str,
+    color: &'a str,
 }
 
-impl Instruction {
-    pub fn into_correct_instruction(&self) -> Result<Instruction> {
+impl<'a> Instruction<'a> {
+    pub fn into_correct_instruction(&self) -> Result<Instruction<'a>> {
         let amount = usize::from_str_radix(&self.color[0..5], 16)?;
         let direction = &self.color[5..];
 
@@ -0,0 +1,113 @@
+use crate::{util::parse::Source, *};
+use anyhow::Result;
+use fastrand::Rng;
+use itertools::Itertools;
+pub use lagoon::*;
+use std::ops::RangeInclusive;
+
+pub mod lagoon;
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Vec<Instruction<'a>>;
+    type Answer = usize;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 62;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 952408144115;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let mut lagoon = Lagoon::new();
+
+        lagoon.dig_trench(input).count()
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let mut lagoon = Lagoon::new();
+
+        lagoon.dig_trench(
+            input
+                .into_iter()
+                .map(|i| i.into_correct_instruction())
+                .try_collect()?,
+        );
+
+        lagoon.count()
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        Source(data).lines(Instruction::try_from)
+    }
+
+    /// A dig plan of `4 * size` instructions, whose colors hide a second plan just as long
+    /// with the distances part 2 expects.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(1);
+        let small = outline(rng, size, 10);
+        let large = outline(rng, size, 0x7ffff);
+        let plan = small
+            .into_iter()
+            .zip(large)
+            .map(|((direction, distance), color)| {
+                let code = "RDLU".find(color.0).unwrap();
+                format!("{} {} (#{:05x}{})", direction, distance, color.1, code)
+            });
+        Some(plan.collect_vec().join("\n"))
+    }
+}
+
+/// The edges of a lagoon shaped like a bar chart above and below a line, `columns` bars
+/// wide and up to `max` tall each way, going clockwise from the bottom left.
+fn outline(rng: &mut Rng, columns: usize, max: isize) -> Vec<(char, usize)> {
+    // Neighbouring bars differ in height, so every edge is at least one long
+    let mut heights = |range: RangeInclusive<isize>| {
+        let mut heights: Vec<isize> = vec![];
+        while heights.len() < columns {
+            let height = rng.isize(range.clone());
+            if heights.last() != Some(&height) {
+                heights.push(height);
+            }
+        }
+        heights
+    };
+    let tops = heights(1..=max);
+    let bottoms = heights(-max..=-1);
+    let widths = (0..columns).map(|_| rng.usize(1..=2 * max as usize));
+    let vertical = |from: isize, to: isize| (if to > from { 'U' } else { 'D' }, from.abs_diff(to));
+
+    let mut edges = vec![('U', tops[0].abs_diff(bottoms[0]))];
+    for (i, width) in widths.collect_vec().into_iter().enumerate() {
+        edges.push(('R', width));
+        edges.push(match tops.get(i + 1) {
+            Some(&next) => vertical(tops[i], next),
+            None => ('D', tops[i].abs_diff(bottoms[i])),
+        });
+    }
+    for i in (0..columns).rev() {
+        let width = edges[2 * i + 1].1;
+        edges.push(('L', width));
+        if i > 0 {
+            edges.push(vertical(bottoms[i], bottoms[i - 1]));
+        }
+    }
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{util::generate, *};
+use anyhow::*;
+use fastrand::Rng;
+use itertools::Itertools;
+pub use xmas::*;
+
+pub mod xmas;
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = XMAS<'a>;
+    type Answer = usize;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 19114;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 167409079868000;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let err = || anyhow!("Failed to find sum");
+        input.run().ok_or_else(err)
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(input.count_accepted_ranges())
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        data.try_into()
+    }
+
+    /// `size` workflows, which only send parts on to later ones so they never loop, and
+    /// `size` parts to sort with them.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(1);
+        // Labels get longer once two letters run out, as the real input's do
+        let len = (2..).find(|&len| 26usize.pow(len) >= 2 * size).unwrap();
+        let others = generate::words(rng, size - 1, len as usize, Rng::lowercase, &["in"]);
+        let labels = [vec!["in".to_string()], others].concat();
+        let destination = |rng: &mut Rng, from: usize| match rng.usize(from + 1..size + 2) {
+            i if i == size => "A",
+            i if i == size + 1 => "R",
+            i => &labels[i],
+        };
+
+        let workflows = (0..size).map(|i| {
+            let rules = (0..rng.usize(1..=3)).map(|_| {
+                let category = b"xmas"[rng.usize(..4)] as char;
+                let comparison = if rng.bool() { '<' } else { '>' };
+                let value = rng.u32(2..=3999);
+                format!(
+                    "{}{}{}:{}",
+                    category,
+                    comparison,
+                    value,
+                    destination(rng, i)
+                )
+            });
+            let rules = rules.collect_vec().join(",");
+            format!("{}{{{},{}}}", labels[i], rules, destination(rng, i))
+        });
+        let workflows = workflows.collect_vec().join("\n");
+
+        let parts = (0..size).map(|_| {
+            let [x, m, a, s] = [(); 4].map(|_| rng.u32(1..=4000));
+            format!("{{x={},m={},a={},s={}}}", x, m, a, s)
+        });
+        Some(format!(
+            "{}\n\n{}",
+            workflows,
+            parts.collect_vec().join("\n")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
@@ -1,5 +1,6 @@
+use crate::util::parse::Source;
 use anyhow::*;
-use itertools::{process_results, Itertools};
+use itertools::Itertools;
 use std::{array, collections::HashMap, ops::RangeInclusive};
 
 const INITIAL_WORKFLOW: &'static str = "in";
@@ -7,11 +8,11 @@ const MINIMAL_VALUE: usize = 1;
 const MAXIMAL_VALUE: usize = 4000;
 
 #[derive(Debug, Clone)]
-pub struct XMAS {
+pub struct XMAS<'a> {
     parts: Vec<Part>,
-    workflows: HashMap<&'static str, Workflow>,
+    workflows: HashMap<&'a str, Workflow<'a>>,
 }
-impl XMAS {
+impl XMAS<'_> {
     pub fn run(&self) -> Option<usize> {
         self.parts.iter().map(|part| self.run_part(part)).sum()
     }
@@ -20,9 +21,19 @@ impl XMAS {
         self.count_accepted_ranges_recursive(&Destination::Workflow("in"), PartRange::new())
     }
 
+    /// Runs each part in `ranges` through the workflows one by one, rather than splitting
+    /// the ranges at every rule.
+    #[cfg(test)]
+    fn count_accepted_naive(&self, ranges: &PartRange) -> usize {
+        let parts = ranges.0.iter().cloned().multi_cartesian_product();
+        parts
+            .filter(|ratings| self.accepts(&Part(ratings[..].try_into().unwrap())) == Some(true))
+            .count()
+    }
+
     fn count_accepted_ranges_recursive(
         &self,
-        destination: &Destination,
+        destination: &Destination<'_>,
         mut ranges: PartRange,
     ) -> usize {
         if !ranges.valid() {
@@ -47,6 +58,10 @@ impl XMAS {
     }
 
     fn run_part(&self, part: &Part) -> Option<usize> {
+        Some(if self.accepts(part)? { part.value() } else { 0 })
+    }
+
+    fn accepts(&self, part: &Part) -> Option<bool> {
         let mut current_workflow = INITIAL_WORKFLOW;
 
         while let Some(workflow) = self.workflows.get(current_workflow) {
@@ -56,8 +71,8 @@ impl XMAS {
                 .find(|rule| part.matches(&rule.condition))?;
 
             match rule.destination {
-                Destination::Accepted => return Some(part.value()),
-                Destination::Rejected => return Some(0),
+                Destination::Accepted => return Some(true),
+                Destination::Rejected => return Some(false),
                 Destination::Workflow(label) => current_workflow = label,
             }
         }
@@ -65,18 +80,19 @@ impl XMAS {
         None
     }
 }
-impl TryFrom<&'static str> for XMAS {
+impl<'a> TryFrom<&'a str> for XMAS<'a> {
     type Error = anyhow::Error;
 
-    fn try_from(s: &'static str) -> Result<Self, Self::Error> {
-        let err = || anyhow!("invalid input");
-        let (workflows, parts) = s.split_once("\n\n").ok_or_else(err)?;
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let src = Source(s);
+        let (workflows, parts) = src.split_once(s, "\n\n")?;
 
-        let parts = parts.lines().map(|line| line.try_into()).try_collect()?;
-        let workflows = process_results(workflows.lines().map(|line| line.try_into()), |it| {
-            it.map(|workflow: Workflow| (workflow.label, workflow))
-                .collect()
-        })?;
+        let parts = src.parse_each(parts.lines(), Part::try_from)?;
+        let workflows = src
+            .parse_each(workflows.lines(), Workflow::try_from)?
+            .into_iter()
+            .map(|workflow| (workflow.label, workflow))
+            .collect();
 
         Ok(Self { parts, workflows })
     }
@@ -85,22 +101,21 @@ impl TryFrom<&'static str> for XMAS {
 #[derive(Debug, Clone)]
 struct Part([usize; 4]);
 
-impl TryFrom<&'static str> for Part {
+impl TryFrom<&str> for Part {
     type Error = anyhow::Error;
 
-    fn try_from(s: &'static str) -> Result<Self, Self::Error> {
-        let err = || anyhow!("invalid input");
-        let without_curlies = &s[1..s.len() - 1];
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let src = Source(s);
+        let without_curlies = src.strip_prefix(s, "{")?;
+        let without_curlies = src.strip_suffix(without_curlies, "}")?;
 
-        let (x, m, a, s) = process_results(
-            without_curlies.split(',').map(|part| {
-                let value = &part[2..];
-                value.parse().map_err(|_| err())
-            }),
-            |it| it.collect_tuple(),
-        )?
-        .ok_or_else(err)?;
-        Ok(Self([x, m, a, s]))
+        let fields = src.fields::<4>(without_curlies, without_curlies.split(','), "a rating")?;
+        let mut ratings = [0; 4];
+        for ((rating, field), name) in ratings.iter_mut().zip(fields).zip(["x=", "m=", "a=", "s="])
+        {
+            *rating = src.parse(src.strip_prefix(field, name)?, "a rating")?;
+        }
+        Ok(Self(ratings))
     }
 }
 
@@ -119,21 +134,21 @@ impl Part {
 }
 
 #[derive(Debug, Clone)]
-struct Workflow {
-    label: &'static str,
-    rules: Vec<Rule>,
+struct Workflow<'a> {
+    label: &'a str,
+    rules: Vec<Rule<'a>>,
 }
 
-impl TryFrom<&'static str> for Workflow {
+impl<'a> TryFrom<&'a str> for Workflow<'a> {
     type Error = anyhow::Error;
 
-    fn try_from(s: &'static str) -> Result<Self, Self::Error> {
-        let err = || anyhow!("invalid input");
-        let (label, rules) = s.split_once("{").ok_or_else(err)?;
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let src = Source(s);
+        let (label, rules) = src.split_once(s, "{")?;
 
-        let rules = &rules[..rules.len() - 1];
+        let rules = src.strip_suffix(rules, "}")?;
 
-        let rules = rules.split(",").map(|rule| rule.try_into()).try_collect()?;
+        let rules = src.parse_each(rules.split(","), Rule::try_from)?;
 
         Ok(Self { label, rules })
     }
@@ -148,16 +163,16 @@ enum Property {
 }
 
 #[derive(Debug, Clone)]
-struct Rule {
+struct Rule<'a> {
     condition: Condition,
-    destination: Destination,
+    destination: Destination<'a>,
 }
 
-impl TryFrom<&'static str> for Rule {
+impl<'a> TryFrom<&'a str> for Rule<'a> {
     type Error = anyhow::Error;
 
-    fn try_from(s: &'static str) -> std::prelude::v1::Result<Self, Self::Error> {
-        let err = || anyhow!("invalid input");
+    fn try_from(s: &'a str) -> std::prelude::v1::Result<Self, Self::Error> {
+        let src = Source(s);
         match s.split_once(":") {
             None => Ok(Self {
                 condition: Always,
@@ -168,18 +183,22 @@ impl TryFrom<&'static str> for Rule {
                 },
             }),
             Some((condition, destination)) => {
-                let property = match &condition[..1] {
+                let short = || src.error(condition, "a condition like `x>10`");
+                let (property, rest) = condition.split_at_checked(1).ok_or_else(short)?;
+                let (comparison, value) = rest.split_at_checked(1).ok_or_else(short)?;
+
+                let property = match property {
                     "x" => Property::X,
                     "m" => Property::M,
                     "a" => Property::A,
                     "s" => Property::S,
-                    _ => return Err(err()),
+                    found => return Err(src.error(found, "one of `xmas`").into()),
                 };
-                let value = condition[2..].parse().map_err(|_| err())?;
-                let condition = match &condition[1..2] {
+                let value = src.parse(value, "a rating")?;
+                let condition = match comparison {
                     ">" => Gt(property, value),
                     "<" => Lt(property, value),
-                    _ => return Err(err()),
+                    found => return Err(src.error(found, "`<` or `>`").into()),
                 };
 
                 let destination = match destination {
@@ -207,8 +226,8 @@ enum Condition {
 use Condition::*;
 
 #[derive(Debug, Clone)]
-enum Destination {
-    Workflow(&'static str),
+enum Destination<'a> {
+    Workflow(&'a str),
     Accepted,
     Rejected,
 }
@@ -259,6 +278,17 @@ impl PartRange {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{days::day19::Day, util::parse::ParseError, BasicSolution};
+    use fastrand::Rng;
+
+    #[test]
+    fn short_conditions_are_parse_errors() {
+        for (rule, column) in [("x:A", 1), (":R", 1), ("é>1:A", 1), ("m>:R", 3)] {
+            let error = Rule::try_from(rule).unwrap_err();
+            let error = error.downcast_ref::<ParseError>().unwrap();
+            assert_eq!((error.line, error.column), (1, column), "{}", rule);
+        }
+    }
 
     #[test]
     fn range_1() {
@@ -290,4 +320,40 @@ mod tests {
         assert_eq!(pass, range);
         assert!(!fail.valid());
     }
+
+    /// Small ranges in every property, each around a value some rule checks, so the
+    /// conditions cut through them.
+    fn small_ranges(rng: &mut Rng, xmas: &XMAS) -> PartRange {
+        let mut thresholds: [Vec<usize>; 4] = Default::default();
+        let conditions = xmas.workflows.values().flat_map(|workflow| &workflow.rules);
+        for rule in conditions {
+            if let Gt(property, value) | Lt(property, value) = rule.condition {
+                thresholds[property as usize].push(value);
+            }
+        }
+        PartRange(thresholds.map(|values| {
+            let around = rng.choice(values).unwrap_or(MINIMAL_VALUE);
+            let start = around.saturating_sub(rng.usize(0..4)).max(MINIMAL_VALUE);
+            start..=start + rng.usize(0..6)
+        }))
+    }
+
+    #[test]
+    fn split_ranges_match_running_every_part() -> Result<()> {
+        for seed in 0..100 {
+            let mut rng = Rng::with_seed(seed);
+            let input = Day::generate(&mut rng, 5).unwrap();
+            let xmas = XMAS::try_from(input.as_str())?;
+            let ranges = small_ranges(&mut rng, &xmas);
+
+            assert_eq!(
+                xmas.count_accepted_ranges_recursive(&Workflow(INITIAL_WORKFLOW), ranges.clone()),
+                xmas.count_accepted_naive(&ranges),
+                "{:?} through\n{}",
+                ranges,
+                input
+            );
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use crate::{util::generate, *};
+use anyhow::*;
+use fastrand::Rng;
+use itertools::Itertools;
+pub use modules::*;
+use num::Integer;
+
+pub mod modules;
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Circuit<'a>;
+    type Answer = usize;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 11687500;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 1;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(mut input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let mut high = 0;
+        let mut low = 0;
+
+        for _ in 0..1000 {
+            let CircuitResult {
+                high: cycle_high,
+                low: cycle_low,
+                circuit,
+            } = input.press_the_button(&mut HashMap::new());
+            high += cycle_high;
+            low += cycle_low;
+            input = circuit;
+        }
+
+        Ok(high * low)
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let module_to_track = input
+            .modules
+            .values()
+            .find_map(|module| module.destinations.contains(&"rx").then_some(module.name))
+            .expect("Module pointing to rx not found");
+
+        let Some(Module {
+            module_type: ModuleType::Conjunction(inputs),
+            ..
+        }) = input.modules.get(module_to_track)
+        else {
+            bail!("Expected module pointing to rx to be a conjunction")
+        };
+
+        let mut minimal_presses: HashMap<&&str, Option<usize>> = inputs
+            .into_iter()
+            .map(|(name, _)| (name, None))
+            .collect::<HashMap<_, _>>();
+
+        let mut circuit = input.clone();
+
+        for press in 1..10_000 {
+            let mut got_high_press = minimal_presses.iter().map(|(&&k, _)| (k, false)).collect();
+
+            let CircuitResult {
+                circuit: next_circuit,
+                ..
+            } = circuit.press_the_button(&mut got_high_press);
+
+            for (name, signal) in got_high_press.iter() {
+                if !signal {
+                    continue;
+                }
+                let minimal_press = minimal_presses
+                    .get_mut(name)
+                    .expect("Module not found in minimal presses");
+
+                *minimal_press = Some(press);
+
+                if minimal_presses.values().all(|value| value.is_some()) {
+                    return Ok(minimal_presses
+                        .values()
+                        .map(|v| v.unwrap())
+                        .fold(1, |a, b| a.lcm(&b)));
+                }
+            }
+
+            circuit = next_circuit;
+        }
+
+        bail!("Failed to find minimal presses after 10k tries")
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        data.try_into()
+    }
+
+    /// `size` binary counters like the real input's (up to 4, so the answer fits), each a
+    /// chain of flip-flops and a conjunction that resets them every so many presses, feeding
+    /// through an inverter into the conjunction in front of `rx`.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let counters = size.clamp(1, 4);
+        // Counters all as wide, so none fires twice before the slowest fires once, which
+        // part 2 relies on
+        let bits = rng.usize(4..=12);
+        let count = counters * (bits + 2) + 1;
+        let mut names = generate::words(rng, count, 2, Rng::lowercase, &["rx"]).into_iter();
+        let last = names.next().unwrap();
+
+        let mut lines = vec![];
+        let mut starts = vec![];
+        for _ in 0..counters {
+            // Odd, with the top bit set, so every flip-flop takes part
+            let period = rng.usize(1 << (bits - 1)..1 << bits) | 1;
+            let flip_flops = names.by_ref().take(bits).collect_vec();
+            let (conjunction, inverter) = (names.next().unwrap(), names.next().unwrap());
+
+            let mut resets = vec![inverter.clone()];
+            for (bit, flip_flop) in flip_flops.iter().enumerate() {
+                let mut destinations = flip_flops.get(bit + 1).into_iter().cloned().collect_vec();
+                if period >> bit & 1 == 1 {
+                    destinations.push(conjunction.clone());
+                }
+                if bit == 0 || period >> bit & 1 == 0 {
+                    resets.push(flip_flop.clone());
+                }
+                lines.push(format!("%{} -> {}", flip_flop, destinations.join(", ")));
+            }
+            lines.push(format!("&{} -> {}", conjunction, resets.join(", ")));
+            lines.push(format!("&{} -> {}", inverter, last));
+            starts.push(flip_flops[0].clone());
+        }
+        lines.push(format!("&{} -> rx", last));
+        lines.push(format!("broadcaster -> {}", starts.join(", ")));
+        rng.shuffle(&mut lines);
+
+        Some(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
@@ -1,13 +1,14 @@
+use crate::util::parse::Source;
 use anyhow::*;
 use std::collections::{HashMap, VecDeque};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Circuit {
-    pub modules: HashMap<&'static str, Module>,
+pub struct Circuit<'a> {
+    pub modules: HashMap<&'a str, Module<'a>>,
 }
 
-impl Circuit {
-    pub fn press_the_button(&self, track: &mut HashMap<&'static str, bool>) -> CircuitResult {
+impl<'a> Circuit<'a> {
+    pub fn press_the_button(&self, track: &mut HashMap<&'a str, bool>) -> CircuitResult<'a> {
         let mut circuit = self.clone();
         let mut high = 0;
         let mut low = 1; // start at 1 because the button is always low
@@ -53,20 +54,19 @@ impl Circuit {
     }
 }
 
-pub struct CircuitResult {
-    pub circuit: Circuit,
+pub struct CircuitResult<'a> {
+    pub circuit: Circuit<'a>,
     pub high: usize,
     pub low: usize,
 }
 
-impl TryFrom<&'static str> for Circuit {
+impl<'a> TryFrom<&'a str> for Circuit<'a> {
     type Error = anyhow::Error;
 
-    fn try_from(value: &'static str) -> Result<Self, Self::Error> {
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
         let mut modules_without_conjunction_dests = HashMap::new();
 
-        for line in value.lines() {
-            let module: Module = line.try_into()?;
+        for module in Source(value).lines(Module::try_from)? {
             modules_without_conjunction_dests.insert(module.name, module);
         }
 
@@ -114,9 +114,9 @@ impl TryFrom<&'static str> for Circuit {
     }
 }
 
-struct Pulse {
+struct Pulse<'a> {
     value: PulseValue,
-    from: &'static str,
+    from: &'a str,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -127,17 +127,17 @@ pub enum PulseValue {
 use PulseValue::*;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum ModuleType {
+pub enum ModuleType<'a> {
     FlipFlop(bool),
-    Conjunction(Vec<(&'static str, PulseValue)>),
+    Conjunction(Vec<(&'a str, PulseValue)>),
     Broadcast,
     Null,
 }
 
 use ModuleType::*;
 
-impl ModuleType {
-    fn process(&mut self, Pulse { value, from }: Pulse) -> Option<PulseValue> {
+impl ModuleType<'_> {
+    fn process(&mut self, Pulse { value, from }: Pulse<'_>) -> Option<PulseValue> {
         match self {
             FlipFlop(ref mut state) => match value {
                 High => None,
@@ -167,18 +167,17 @@ impl ModuleType {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Module {
-    pub name: &'static str,
-    pub module_type: ModuleType,
-    pub destinations: Vec<&'static str>,
+pub struct Module<'a> {
+    pub name: &'a str,
+    pub module_type: ModuleType<'a>,
+    pub destinations: Vec<&'a str>,
 }
 
-impl TryFrom<&'static str> for Module {
+impl<'a> TryFrom<&'a str> for Module<'a> {
     type Error = anyhow::Error;
 
-    fn try_from(s: &'static str) -> Result<Self, Self::Error> {
-        let err = |s| anyhow!("Invalid module: {}", s);
-        let (type_name, destinations) = s.split_once(" -> ").ok_or_else(|| err(s))?;
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let (type_name, destinations) = Source(s).split_once(s, " -> ")?;
         let destinations = destinations.split(", ").collect::<Vec<_>>();
 
         let (module_type, name) = match type_name {
@@ -1,4 +1,6 @@
-use crate::vec3::*;
+use super::vec3::*;
+use crate::span;
+use crate::util::parse::Source;
 use anyhow::*;
 use std::{collections::HashSet, str::FromStr};
 
@@ -11,6 +13,7 @@ pub struct Space {
 
 impl Space {
     pub fn resting_positions(bricks: &mut [Brick]) -> Result<Self> {
+        let _span = span::enter("settle bricks");
         bricks.sort_by_key(|brick| brick.start.z);
         let mut grid: Vec<[[Option<usize>; 32]; 32]> = vec![];
 
@@ -99,11 +102,11 @@ impl FromStr for Brick {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         // 1,2,3~4,5,6
-        let err = || anyhow!("invalid brick: {}", s);
-        let (from, to) = s.split_once('~').ok_or_else(err)?;
+        let src = Source(s);
+        let (from, to) = src.split_once(s, "~")?;
 
-        let start = from.parse()?;
-        let to: V3 = to.parse()?;
+        let start = from.parse().map_err(|error| src.within(error, from))?;
+        let to: V3 = to.parse().map_err(|error| src.within(error, to))?;
 
         let size = to - start;
 
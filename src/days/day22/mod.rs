@@ -0,0 +1,116 @@
+use crate::{util::parse::parse_lines, *};
+use anyhow::*;
+pub use bricks::*;
+use fastrand::Rng;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+pub mod bricks;
+pub mod vec3;
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = (Space, Vec<Brick>);
+    type Answer = usize;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 5;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 7;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let (space, bricks) = input;
+        let mut total = 0;
+
+        for brick_id in 0..bricks.len() {
+            let supporting = space.supporting(brick_id, &bricks);
+            if supporting.len() == 0 {
+                total += 1;
+            }
+        }
+
+        Ok(total)
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        let (_, bricks) = input;
+
+        let mut total = 0;
+
+        for brick_idx in 0..bricks.len() {
+            // the most idiotic thing I can think of :D
+            let mut new_bricks = bricks.clone();
+
+            new_bricks.remove(brick_idx);
+            new_bricks.sort_by_key(|brick| brick.start.z);
+
+            let mut new_bricks_after = new_bricks.clone();
+
+            Space::resting_positions(&mut new_bricks_after)?;
+
+            total += new_bricks_after
+                .into_iter()
+                .zip(new_bricks)
+                .filter(|(a, b)| a != b)
+                .count();
+        }
+
+        Ok(total)
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        let mut bricks = parse_lines(data)?;
+        let space = Space::resting_positions(&mut bricks)?;
+        Ok((space, bricks))
+    }
+
+    /// `size` bricks up to four cubes long, floating apart in a column 4 by 4 across.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut filled = HashSet::new();
+        let mut bricks = vec![];
+        while bricks.len() < size {
+            let start = [
+                rng.isize(0..4),
+                rng.isize(0..4),
+                rng.isize(1..=size as isize),
+            ];
+            let (axis, len) = (rng.usize(..3), rng.isize(0..4));
+            let cubes = (0..=len)
+                .map(|i| {
+                    let mut cube = start;
+                    cube[axis] += i;
+                    cube
+                })
+                .collect_vec();
+            let end = cubes[cubes.len() - 1];
+            if end[0] >= 4 || end[1] >= 4 || cubes.iter().any(|cube| filled.contains(cube)) {
+                continue;
+            }
+            filled.extend(cubes);
+            bricks.push(format!(
+                "{}~{}",
+                start.iter().join(","),
+                end.iter().join(",")
+            ));
+        }
+        Some(bricks.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
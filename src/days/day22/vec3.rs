@@ -3,8 +3,8 @@ use std::{
     str::FromStr,
 };
 
+use crate::util::parse::Source;
 use anyhow::*;
-use itertools::{process_results, Itertools};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct V3 {
@@ -17,10 +17,7 @@ impl FromStr for V3 {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let err = || anyhow!("invalid vector: {}", s);
-        let (x, y, z) = process_results(s.split(',').map(|s| s.parse()), |it| {
-            it.collect_tuple().ok_or_else(err)
-        })??;
+        let [x, y, z] = Source(s).parse_fields(s, s.split(','), "a coordinate")?;
         Ok(Self { x, y, z })
     }
 }
@@ -5,8 +5,11 @@ use std::sync::atomic::AtomicU32;
 use std::sync::atomic::Ordering;
 use std::thread;
 
+use crate::cancel::{self, CancellationToken};
+use crate::span;
 use crate::util::grid::*;
 use crate::util::point::*;
+use anyhow::Result;
 
 const MAX_JUNCTIONS: usize = 36;
 
@@ -48,7 +51,8 @@ impl Garden {
         costs[self.end] + self.extra_cost
     }
 
-    pub fn longest_distance_undirected(&self) -> u32 {
+    pub fn longest_distance_undirected(&self) -> Result<u32> {
+        let token = cancel::current();
         let shared = AtomicU32::new(0);
         let threads = thread::available_parallelism().unwrap().get();
 
@@ -77,22 +81,29 @@ impl Garden {
         }
         thread::scope(|scope| {
             for start in &seeds {
-                scope.spawn(|| worker(self, &shared, start));
+                scope.spawn(|| worker(self, &shared, start, &token));
             }
         });
 
-        shared.load(Ordering::Relaxed) + self.extra_cost
+        token.check()?;
+        Ok(shared.load(Ordering::Relaxed) + self.extra_cost)
     }
 }
 
-fn worker(garden: &Garden, shared: &AtomicU32, start: &(usize, u64, u32)) {
+fn worker(
+    garden: &Garden,
+    shared: &AtomicU32,
+    start: &(usize, u64, u32),
+    token: &CancellationToken,
+) {
     let (from, visited, cost) = *start;
-    let result = dfs(garden, from, visited);
+    let result = dfs(garden, from, visited, token);
     shared.fetch_max(result + cost, Ordering::Relaxed);
 }
 
-fn dfs(garden: &Garden, from: usize, visited: u64) -> u32 {
-    if from == garden.end {
+// Exponential in the number of junctions, so it checks for cancellation on every call
+fn dfs(garden: &Garden, from: usize, visited: u64, token: &CancellationToken) -> u32 {
+    if from == garden.end || token.is_cancelled() {
         return 0;
     }
 
@@ -104,7 +115,7 @@ fn dfs(garden: &Garden, from: usize, visited: u64) -> u32 {
         let mask = 1 << to;
         nodes ^= mask;
 
-        cost = cost.max(garden.weights[from][to] + dfs(garden, to, visited | mask));
+        cost = cost.max(garden.weights[from][to] + dfs(garden, to, visited | mask, token));
     }
 
     cost
@@ -114,9 +125,10 @@ impl FromStr for Garden {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut grid = Grid::parse(s);
+        let mut grid = Grid::parse(s)?;
         let width = grid.width;
         let height = grid.height;
+        anyhow::ensure!(width >= 3 && height >= 3, "The garden is too small");
 
         // this avoids a bounds check
         grid[Point::new(1, 0)] = WALL;
@@ -133,6 +145,7 @@ impl FromStr for Garden {
         points_of_interest.insert(start, 0);
         points_of_interest.insert(end, 1);
 
+        let finding = span::enter("find junctions");
         for y in 1..height - 1 {
             for x in 1..width - 1 {
                 let point = Point::new(x, y);
@@ -141,10 +154,9 @@ impl FromStr for Garden {
                 if tile == WALL {
                     continue;
                 }
-                let neighbors = ADJACENT
-                    .iter()
-                    .map(|&dir| point + dir)
-                    .filter(|&p| grid[p] != WALL)
+                let neighbors = grid
+                    .neighbors4(point)
+                    .filter(|&(_, &tile)| tile != WALL)
                     .count();
 
                 if neighbors > 2 {
@@ -153,7 +165,9 @@ impl FromStr for Garden {
                 }
             }
         }
+        drop(finding);
 
+        let compressing = span::enter("compress junction graph");
         let mut frontier = VecDeque::new();
         let mut directed: [u64; 36] = [0; MAX_JUNCTIONS];
         let mut undirected: [u64; 36] = [0; MAX_JUNCTIONS];
@@ -166,7 +180,9 @@ impl FromStr for Garden {
             while let Some((point, cost, forwards)) = frontier.pop_front() {
                 for direction in ADJACENT {
                     let next = point + direction;
-                    let tile = grid[next];
+                    let Some(&tile) = grid.get(next) else {
+                        continue;
+                    };
                     let next_cost = cost + 1;
 
                     match tile {
@@ -199,6 +215,7 @@ impl FromStr for Garden {
                 }
             }
         }
+        drop(compressing);
 
         let start = undirected[0].trailing_zeros() as usize;
         let end = undirected[1].trailing_zeros() as usize;
@@ -0,0 +1,92 @@
+use crate::*;
+use anyhow::*;
+use fastrand::Rng;
+pub use garden::*;
+use itertools::Itertools;
+
+pub mod garden;
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Garden;
+    type Answer = u32;
+    type TestAnswer = Self::Answer;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 94;
+    const SAMPLE_ANSWER_B: Self::TestAnswer = 154;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        Ok(input.longest_distance())
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
+        input.longest_distance_undirected()
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        data.parse()
+    }
+
+    /// A maze like the real input's, whose junctions make a lattice `size / 2` across (2 to
+    /// 6) joined by straight corridors of random lengths, and sloped so you can only walk
+    /// them right or down.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let across = (size / 2).clamp(2, 6);
+        let (mut xs, mut ys) = (vec![1], vec![rng.usize(3..=5)]);
+        for _ in 1..across {
+            xs.push(xs[xs.len() - 1] + rng.usize(2..=8));
+            ys.push(ys[ys.len() - 1] + rng.usize(2..=8));
+        }
+        let (first, last) = ((xs[0], ys[0]), (xs[across - 1], ys[across - 1]));
+        let (width, height) = (last.0 + 2, last.1 + 4);
+
+        let mut grid = vec![vec!['#'; width]; height];
+        // In from the top left, and out at the bottom right
+        for row in &mut grid[..first.1] {
+            row[first.0] = '.';
+        }
+        grid[first.1 - 1][first.0] = 'v';
+        for row in &mut grid[last.1 + 1..] {
+            row[last.0] = '.';
+        }
+        grid[last.1 + 1][last.0] = 'v';
+
+        for (i, &y) in ys.iter().enumerate() {
+            for (j, &x) in xs.iter().enumerate() {
+                grid[y][x] = '.';
+                if let Some(&next) = xs.get(j + 1) {
+                    grid[y][x + 1..next].fill('.');
+                    grid[y][x + 1] = '>';
+                    grid[y][next - 1] = '>';
+                }
+                if let Some(&next) = ys.get(i + 1) {
+                    for row in &mut grid[y + 1..next] {
+                        row[x] = '.';
+                    }
+                    grid[y + 1][x] = 'v';
+                    grid[next - 1][x] = 'v';
+                }
+            }
+        }
+
+        Some(grid.into_iter().map(String::from_iter).join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
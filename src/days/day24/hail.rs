@@ -0,0 +1,53 @@
+use crate::util::parse::Source;
+use anyhow::*;
+use std::{ops::RangeInclusive, str::FromStr};
+
+use super::vec3::Vec3;
+
+#[derive(Debug, Clone)]
+pub struct Hailstone {
+    pub position: Vec3,
+    pub velocity: Vec3,
+}
+
+impl FromStr for Hailstone {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let src = Source(s);
+        let (pos, vel) = src.split_once(s, " @ ")?;
+        let [x, y, z] = src.parse_fields(pos, pos.split(", ").map(str::trim), "a coordinate")?;
+        let [dx, dy, dz] = src.parse_fields(vel, vel.split(", ").map(str::trim), "a velocity")?;
+        let position = Vec3::new(x, y, z);
+        let velocity = Vec3::new(dx, dy, dz);
+        Ok(Self { position, velocity })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Hail {
+    pub stones: Vec<Hailstone>,
+    pub testing_area: RangeInclusive<f64>,
+}
+
+impl FromStr for Hail {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let src = Source(s);
+        // Yeah, I've modified the input so that I can test the example
+        // So sue me.
+        let (area_str, stones_str) = src.split_once(s, "\n\n")?;
+        let (from, to) = src.split_once(area_str, "..")?;
+        let from = src.parse::<f64>(from, "a number")?;
+        let to = src.parse::<f64>(to, "a number")?;
+        let testing_area = from..=to;
+
+        let stones = src.parse_each(stones_str.lines().map(str::trim), Hailstone::from_str)?;
+
+        Ok(Self {
+            stones,
+            testing_area,
+        })
+    }
+}
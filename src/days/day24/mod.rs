@@ -0,0 +1,151 @@
+use crate::*;
+use anyhow::*;
+use fastrand::Rng;
+pub use hail::Hail;
+use itertools::Itertools;
+pub use line2d::Line2D;
+use std::collections::HashSet;
+use z3::{
+    ast::{Ast, Int},
+    Config, Context, Solver,
+};
+
+pub mod hail;
+pub mod line2d;
+pub mod vec3;
+
+pub struct Day;
+
+impl BasicSolution for Day {
+    type Parsed<'a> = Hail;
+    type Answer = usize;
+    type Answer2 = i64;
+    type TestAnswer2 = Self::Answer2;
+
+    const DATA: &'static str = include_str!("input.txt");
+    const SAMPLE_DATA: &'static str = include_str!("sample.txt");
+    const SAMPLE_ANSWER_A: Self::TestAnswer1 = 2;
+    const SAMPLE_ANSWER_B: Self::TestAnswer2 = 47;
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer1> {
+        let Hail {
+            stones,
+            testing_area,
+        } = input;
+        let lines = stones.into_iter().map(|stone| {
+            Line2D::new(
+                stone.position.into(),
+                (stone.position + stone.velocity).into(),
+            )
+        });
+
+        Ok(lines
+            .clone()
+            .combinations(2)
+            .map(|v| (v[0], v[1]))
+            .filter_map(|(a, b)| Some((a, b, a.intersection_point(&b)?)))
+            .filter_map(|(a, b, (x, y))| {
+                if a.is_point_after_start((x, y)) && b.is_point_after_start((x, y)) {
+                    Some((x, y))
+                } else {
+                    None
+                }
+            })
+            .filter(|(x, y)| testing_area.contains(x) && testing_area.contains(y))
+            .count())
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer2> {
+        let cfg = Config::new();
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+
+        let stones = input.stones;
+
+        let px = Int::new_const(&ctx, "px");
+        let py = Int::new_const(&ctx, "py");
+        let pz = Int::new_const(&ctx, "pz");
+        let vx = Int::new_const(&ctx, "vx");
+        let vy = Int::new_const(&ctx, "vy");
+        let vz = Int::new_const(&ctx, "vz");
+
+        for stone in stones {
+            let px_n = Int::from_i64(&ctx, stone.position.x);
+            let py_n = Int::from_i64(&ctx, stone.position.y);
+            let pz_n = Int::from_i64(&ctx, stone.position.z);
+            let vx_n = Int::from_i64(&ctx, stone.velocity.x);
+            let vy_n = Int::from_i64(&ctx, stone.velocity.y);
+            let vz_n = Int::from_i64(&ctx, stone.velocity.z);
+            let t_n = Int::fresh_const(&ctx, "t");
+
+            solver.assert(&(&px_n + &vx_n * &t_n)._eq(&(&px + &vx * &t_n)));
+            solver.assert(&(&py_n + &vy_n * &t_n)._eq(&(&py + &vy * &t_n)));
+            solver.assert(&(&pz_n + &vz_n * &t_n)._eq(&(&pz + &vz * &t_n)));
+        }
+        solver.check();
+
+        let model = solver.get_model().unwrap();
+        let x = model.get_const_interp(&px).unwrap().as_i64().unwrap();
+        let y = model.get_const_interp(&py).unwrap().as_i64().unwrap();
+        let z = model.get_const_interp(&pz).unwrap().as_i64().unwrap();
+
+        Ok(x + y + z)
+    }
+
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+        data.parse()
+    }
+
+    /// `size` hailstones (at least 3), every one of which a rock thrown from a whole-numbered
+    /// position at a whole-numbered velocity hits, each at a different time.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let rock = [(); 3].map(|_| rng.i64(-1000..=1000));
+        let throw = [(); 3].map(|_| rng.i64(-100..=100));
+        let mut times = HashSet::new();
+
+        let mut stones = vec![];
+        while stones.len() < size.max(3) {
+            let time = rng.i64(1..=1000);
+            let velocity = [(); 3].map(|_| rng.i64(-100..=100));
+            if velocity == throw || !times.insert(time) {
+                continue;
+            }
+            let position = [0, 1, 2].map(|i| rock[i] + (throw[i] - velocity[i]) * time);
+            stones.push((position, velocity));
+        }
+
+        let (low, high) = stones
+            .iter()
+            .flat_map(|([x, y, _], _)| [*x, *y])
+            .minmax()
+            .into_option()
+            .unwrap();
+        let stones = stones
+            .iter()
+            .map(|(position, velocity)| {
+                format!(
+                    "{} @ {}",
+                    position.iter().join(", "),
+                    velocity.iter().join(", ")
+                )
+            })
+            .join("\n");
+        Some(format!("{}..{}\n\n{}", low, high, stones))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a() -> anyhow::Result<()> {
+        Day::test_a()
+    }
+
+    #[test]
+    fn b() -> anyhow::Result<()> {
+        Day::test_b()
+    }
+}
@@ -0,0 +1,90 @@
+use crate::runner::Registration;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day14_better;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+// `aoc new` adds days above this line
+
+/// Every day's solution, in the order the runner lists them.
+pub fn registry() -> Vec<Registration> {
+    vec![
+        Registration::new::<day01::Day>(1, ""),
+        Registration::new::<day02::Day>(2, ""),
+        Registration::new::<day03::Day>(3, ""),
+        Registration::new::<day04::Day>(4, ""),
+        Registration::new::<day05::Day>(5, ""),
+        Registration::new::<day06::Day>(6, ""),
+        Registration::new::<day07::Day>(7, ""),
+        Registration::new::<day08::Day>(8, ""),
+        Registration::new::<day09::Day>(9, ""),
+        Registration::new::<day10::Day>(10, ""),
+        Registration::new::<day11::Day>(11, ""),
+        Registration::new::<day12::Day>(12, ""),
+        Registration::new::<day13::Day>(13, ""),
+        Registration::new::<day14::Day>(14, ""),
+        Registration::new::<day14_better::Day>(14, "better"),
+        Registration::new::<day15::Day>(15, ""),
+        Registration::new::<day16::Day>(16, ""),
+        Registration::new::<day17::Day>(17, ""),
+        Registration::new::<day18::Day>(18, ""),
+        Registration::new::<day19::Day>(19, ""),
+        Registration::new::<day20::Day>(20, ""),
+        Registration::new::<day21::Day>(21, ""),
+        Registration::new::<day22::Day>(22, ""),
+        Registration::new::<day23::Day>(23, ""),
+        Registration::new::<day24::Day>(24, ""),
+        // `aoc new` registers days above this line
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::Part;
+    use anyhow::*;
+    use fastrand::Rng;
+    use std::time::Duration;
+
+    #[test]
+    fn generated_inputs_solve() -> Result<()> {
+        for registration in registry() {
+            for seed in 0..3 {
+                let Some(input) = registration.generate(&mut Rng::with_seed(seed), 5) else {
+                    continue;
+                };
+                // Part 2 of day 21 leans on the real garden being 131 plots across
+                let part = (registration.day == 21).then_some(Part::A);
+                let context = || format!("{} on generated input {}", registration.name(), seed);
+                let report = registration
+                    .run(&input, part, Some(Duration::from_secs(10)))
+                    .with_context(context)?;
+                for part in report.parts {
+                    part.answer.with_context(context)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
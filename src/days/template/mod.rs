@@ -1,35 +1,32 @@
 use anyhow::*;
-use aoc_2023::*;
+use crate::*;
 
-struct Day;
+pub struct Day;
 
 impl BasicSolution for Day {
-    type Parsed = &'static str;
+    type Parsed<'a> = &'a str;
     type Answer = u32;
     type TestAnswer = Self::Answer;
 
     const DATA: &'static str = include_str!("input.txt");
     const SAMPLE_DATA: &'static str = include_str!("sample.txt");
-    const SAMPLE_ANSWER_A: Self::TestAnswer = 4361;
+    const SAMPLE_ANSWER_A: Self::TestAnswer = 0;
     const SAMPLE_ANSWER_B: Self::TestAnswer = 0;
+    const ANSWERS: &'static str = include_str!("answers.txt");
 
-    fn part1(input: Self::Parsed) -> Result<Self::Answer> {
+    fn part1(input: Self::Parsed<'_>) -> Result<Self::Answer> {
         todo!()
     }
 
-    fn part2(input: Self::Parsed) -> Result<Self::Answer> {
+    fn part2(input: Self::Parsed<'_>) -> Result<Self::Answer> {
         todo!()
     }
 
-    fn parse(data: &str) -> Result<Self::Parsed> {
+    fn parse(data: &str) -> Result<Self::Parsed<'_>> {
         todo!()
     }
 }
 
-pub fn main() -> anyhow::Result<()> {
-    Day::main()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,158 @@
+use anyhow::*;
+use fastrand::Rng;
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter},
+};
+
+use crate::{
+    options::Part,
+    runner::{Registration, Report},
+};
+
+/// An input to run every implementation of a day on, with the name reports use for it.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub name: String,
+    pub data: Cow<'static, str>,
+}
+
+/// The real input and samples of the day `variants` implement, plus `cases` generated ones
+/// `size` big, seeded with `seed`, `seed + 1` and so on, if one of the variants can generate
+/// them.
+pub fn inputs(variants: &[&Registration], seed: u64, cases: u64, size: usize) -> Vec<Input> {
+    let mut inputs: Vec<Input> = vec![];
+    if let Some(first) = variants.first() {
+        let [sample, sample_b] = first.samples;
+        for (name, data) in [
+            ("input", first.data),
+            ("sample", sample),
+            ("sample b", sample_b),
+        ] {
+            if !data.is_empty() && inputs.iter().all(|input| input.data != data) {
+                inputs.push(Input {
+                    name: name.to_string(),
+                    data: data.into(),
+                });
+            }
+        }
+    }
+
+    for seed in seed..seed + cases {
+        let mut rng = Rng::with_seed(seed);
+        let Some(data) = variants
+            .iter()
+            .find_map(|variant| variant.generate(&mut rng, size))
+        else {
+            break;
+        };
+        inputs.push(Input {
+            name: format!("generated input (seed {})", seed),
+            data: data.into(),
+        });
+    }
+
+    inputs
+}
+
+/// A part of an input that the implementations of a day answered differently.
+#[derive(Debug)]
+pub struct Disagreement {
+    pub input: String,
+    pub part: Part,
+    /// Each variant's name and answer, or error.
+    pub answers: Vec<(String, Result<String, String>)>,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "part {:?} of the {}:", self.part, self.input)?;
+        for (name, answer) in &self.answers {
+            match answer {
+                Result::Ok(answer) => write!(f, "\n  {}: {}", name, answer)?,
+                Err(error) => write!(f, "\n  {}: error: {}", name, error)?,
+            }
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+/// Runs every one of `variants` on every input, in parallel, and returns the parts they
+/// disagree on.
+///
+/// Variants that all fail on a part agree, whatever their errors; one failing where another
+/// answers does not.
+pub fn differential(variants: &[&Registration], inputs: &[Input]) -> Result<Vec<Disagreement>> {
+    ensure!(
+        variants.len() > 1,
+        "Nothing to compare: needs two implementations of a day"
+    );
+
+    Ok(inputs
+        .par_iter()
+        .flat_map_iter(|input| {
+            let reports = variants
+                .iter()
+                .map(|variant| (variant.name(), variant.run(&input.data, None, None)))
+                .collect::<Vec<_>>();
+
+            [Part::A, Part::B].into_iter().filter_map(move |part| {
+                let answers = reports
+                    .iter()
+                    .map(|(name, report)| (name.clone(), answer(report, part)))
+                    .collect::<Vec<_>>();
+                let agree = answers
+                    .iter()
+                    .map(|(_, answer)| answer.as_ref().ok())
+                    .all_equal();
+
+                (!agree).then(|| Disagreement {
+                    input: input.name.clone(),
+                    part,
+                    answers,
+                })
+            })
+        })
+        .collect())
+}
+
+fn answer(report: &Result<Report>, part: Part) -> Result<String, String> {
+    let report = report.as_ref().map_err(|error| error.to_string())?;
+    match report.parts.iter().find(|report| report.part == part) {
+        Some(report) => report
+            .answer
+            .as_ref()
+            .cloned()
+            .map_err(|error| error.to_string()),
+        None => Err("not run".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days, runner};
+
+    #[test]
+    fn day14_variants_agree() -> Result<()> {
+        let registry = days::registry();
+        let variants = runner::select(&registry, "14")?;
+        // The real input is slow to solve with the original implementation
+        let mut inputs = inputs(&variants, 0, 20, 10);
+        inputs.retain(|input| input.name != "input");
+        let generated = inputs
+            .iter()
+            .filter(|input| input.name.starts_with("generated"));
+        assert_eq!(generated.count(), 20);
+
+        let disagreements = differential(&variants, &inputs)?;
+        assert!(
+            disagreements.is_empty(),
+            "{}",
+            disagreements.iter().join("\n")
+        );
+        Ok(())
+    }
+}
@@ -1,101 +1,178 @@
 #![feature(associated_type_defaults)]
-use anyhow::Result;
-use std::{
-    fmt::{Debug, Display},
-    time::Instant,
-};
+use anyhow::{ensure, Result};
+use fastrand::Rng;
+use std::fmt::{Debug, Display};
 
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod cancel;
+pub mod days;
+pub mod diff;
+pub mod memory;
+pub mod options;
+pub mod runner;
+pub mod scaffold;
+pub mod span;
+pub mod watch;
 pub mod util {
+    pub mod generate;
     pub mod grid;
+    pub mod parse;
     pub mod point;
+    pub mod search;
 }
 
+use options::{Format, Options, Part};
+use runner::PartReport;
+use util::parse::eprint_diagnostic;
+
 pub type IResult<T> = Result<T>;
 
 pub trait BasicSolution {
-    type Parsed: Debug + Clone = &'static str;
-    type Answer: Debug + Display + PartialEq<Self::TestAnswer>;
+    /// What `parse` produces, which may borrow from the input it was parsed from.
+    type Parsed<'a>: Debug + Clone = &'a str;
+    /// What both parts answer, unless `Answer1` or `Answer2` says otherwise. The test answers
+    /// default to `TestAnswer` regardless, so overriding `Answer2` usually means overriding
+    /// `TestAnswer2` too.
+    type Answer: Debug + Display;
     type TestAnswer: Debug = Self::Answer;
+    type Answer1: Debug + Display = Self::Answer;
+    type Answer2: Debug + Display = Self::Answer;
+    type TestAnswer1: Debug = Self::TestAnswer;
+    type TestAnswer2: Debug = Self::TestAnswer;
     const DATA: &'static str;
     const SAMPLE_DATA: &'static str;
     const SAMPLE_DATA_B: &'static str = Self::SAMPLE_DATA;
-    const SAMPLE_ANSWER_A: Self::TestAnswer;
-    const SAMPLE_ANSWER_B: Self::TestAnswer;
+    const SAMPLE_ANSWER_A: Self::TestAnswer1;
+    const SAMPLE_ANSWER_B: Self::TestAnswer2;
+    const ANSWERS: &'static str = "";
+    const VERSION: &'static str = "";
+
+    fn parse(data: &str) -> IResult<Self::Parsed<'_>>;
+    fn part1(data: Self::Parsed<'_>) -> Result<Self::Answer1>;
+    fn part2(data: Self::Parsed<'_>) -> Result<Self::Answer2>;
 
-    fn parse(data: &'static str) -> IResult<Self::Parsed>;
-    fn part1(data: Self::Parsed) -> Result<Self::Answer>;
-    fn part2(data: Self::Parsed) -> Result<Self::Answer>;
+    /// A random, valid input for testing, if the day knows how to make one. Bigger `size`s
+    /// make bigger inputs, though what it counts is up to the day; 10 is about sample-sized.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
 }
 
-impl<T: BasicSolution> Solution for T {
-    type Parsed = <Self as BasicSolution>::Parsed;
-    type ParsedTest = Self::Parsed;
-    type Answer = <Self as BasicSolution>::Answer;
-    type TestAnswer = <Self as BasicSolution>::TestAnswer;
+/// Every `BasicSolution` whose sample answers can be checked is a `Solution`. The bounds
+/// live here because defaults can't promise them: `Answer1` could be overridden alone.
+impl<T: BasicSolution> Solution for T
+where
+    T::Answer1: PartialEq<T::TestAnswer1>,
+    T::Answer2: PartialEq<T::TestAnswer2>,
+{
+    type Parsed<'a> = <Self as BasicSolution>::Parsed<'a>;
+    type ParsedTest<'a> = Self::Parsed<'a>;
+    type Answer1 = <Self as BasicSolution>::Answer1;
+    type Answer2 = <Self as BasicSolution>::Answer2;
+    type TestAnswer1 = <Self as BasicSolution>::TestAnswer1;
+    type TestAnswer2 = <Self as BasicSolution>::TestAnswer2;
     const DATA: &'static str = <Self as BasicSolution>::DATA;
     const SAMPLE_DATA: &'static str = <Self as BasicSolution>::SAMPLE_DATA;
     const SAMPLE_DATA_B: &'static str = <Self as BasicSolution>::SAMPLE_DATA_B;
-    const SAMPLE_ANSWER_A: <Self as BasicSolution>::TestAnswer =
+    const SAMPLE_ANSWER_A: <Self as BasicSolution>::TestAnswer1 =
         <Self as BasicSolution>::SAMPLE_ANSWER_A;
-    const SAMPLE_ANSWER_B: <Self as BasicSolution>::TestAnswer =
+    const SAMPLE_ANSWER_B: <Self as BasicSolution>::TestAnswer2 =
         <Self as BasicSolution>::SAMPLE_ANSWER_B;
+    const ANSWERS: &'static str = <Self as BasicSolution>::ANSWERS;
+    const VERSION: &'static str = <Self as BasicSolution>::VERSION;
 
-    fn parse(data: &'static str) -> IResult<Self::Parsed> {
+    fn parse(data: &str) -> IResult<Self::Parsed<'_>> {
         <Self as BasicSolution>::parse(data)
     }
 
-    fn part1(data: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part1(data: Self::Parsed<'_>) -> anyhow::Result<Self::Answer1> {
         <Self as BasicSolution>::part1(data)
     }
 
-    fn part2(data: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part2(data: Self::Parsed<'_>) -> anyhow::Result<Self::Answer2> {
         <Self as BasicSolution>::part2(data)
     }
 
-    fn parse_test(data: &'static str) -> IResult<Self::ParsedTest> {
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        <Self as BasicSolution>::generate(rng, size)
+    }
+
+    fn parse_test(data: &str) -> IResult<Self::ParsedTest<'_>> {
         Self::parse(data)
     }
-    fn a_test(data: Self::ParsedTest) -> anyhow::Result<Self::Answer> {
+    fn a_test(data: Self::ParsedTest<'_>) -> anyhow::Result<Self::Answer1> {
         Self::part1(data)
     }
-    fn b_test(data: Self::ParsedTest) -> anyhow::Result<Self::Answer> {
+    fn b_test(data: Self::ParsedTest<'_>) -> anyhow::Result<Self::Answer2> {
         Self::part2(data)
     }
 }
 
 pub trait Solution {
-    type Parsed: Debug + Clone = &'static str;
-    type ParsedTest: Debug + Clone = Self::Parsed;
-    type Answer: Debug + Display + PartialEq<Self::TestAnswer>;
-    type TestAnswer: Debug = Self::Answer;
+    /// What `parse` produces, which may borrow from the input it was parsed from.
+    type Parsed<'a>: Debug + Clone = &'a str;
+    type ParsedTest<'a>: Debug + Clone = Self::Parsed<'a>;
+    type Answer1: Debug + Display + PartialEq<Self::TestAnswer1>;
+    type Answer2: Debug + Display + PartialEq<Self::TestAnswer2>;
+    type TestAnswer1: Debug;
+    type TestAnswer2: Debug;
     const DATA: &'static str;
     const SAMPLE_DATA: &'static str;
     const SAMPLE_DATA_B: &'static str = Self::SAMPLE_DATA;
-    const SAMPLE_ANSWER_A: Self::TestAnswer;
-    const SAMPLE_ANSWER_B: Self::TestAnswer;
+    const SAMPLE_ANSWER_A: Self::TestAnswer1;
+    const SAMPLE_ANSWER_B: Self::TestAnswer2;
+    /// Known-correct answers for `DATA`, in the format of [`answers::recorded`].
+    const ANSWERS: &'static str = "";
+    /// Bump when a change could alter the answers, so [`cache::Cache`]d ones are recomputed.
+    const VERSION: &'static str = "";
 
-    fn parse(data: &'static str) -> IResult<Self::Parsed>;
-    fn part1(data: Self::Parsed) -> anyhow::Result<Self::Answer>;
-    fn part2(data: Self::Parsed) -> anyhow::Result<Self::Answer>;
-    fn parse_test(data: &'static str) -> IResult<Self::ParsedTest>;
-    fn a_test(data: Self::ParsedTest) -> anyhow::Result<Self::Answer>;
-    fn b_test(data: Self::ParsedTest) -> anyhow::Result<Self::Answer>;
+    fn parse(data: &str) -> IResult<Self::Parsed<'_>>;
+    fn part1(data: Self::Parsed<'_>) -> anyhow::Result<Self::Answer1>;
+    fn part2(data: Self::Parsed<'_>) -> anyhow::Result<Self::Answer2>;
+    fn parse_test(data: &str) -> IResult<Self::ParsedTest<'_>>;
+    fn a_test(data: Self::ParsedTest<'_>) -> anyhow::Result<Self::Answer1>;
+    fn b_test(data: Self::ParsedTest<'_>) -> anyhow::Result<Self::Answer2>;
+
+    /// A random input `size` big, if the day can make one; see [`BasicSolution::generate`].
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
 
-    fn final_parse(data: &'static str) -> Result<Self::Parsed> {
+    fn final_parse(data: &str) -> Result<Self::Parsed<'_>> {
         Self::parse(data)
     }
 
-    fn final_parse_test(data: &'static str) -> Result<Self::ParsedTest> {
+    fn final_parse_test(data: &str) -> Result<Self::ParsedTest<'_>> {
         Self::parse_test(data)
     }
 
+    /// Fails if `answer` differs from the answer recorded for `DATA`.
+    fn verify(part: Part, answer: &str) -> Result<()> {
+        answers::verify(Self::ANSWERS, part, answer)
+    }
+
+    /// Runs one part 1 sample case, naming it in the failure; see [`sample_tests!`].
+    fn check_sample_a(name: &str, data: &str, expected: Self::TestAnswer1) -> Result<()> {
+        let answer = Self::a_test(Self::final_parse_test(data)?)?;
+        check_sample_answer(name, Part::A, answer, expected)
+    }
+
+    /// Runs one part 2 sample case, naming it in the failure; see [`sample_tests!`].
+    fn check_sample_b(name: &str, data: &str, expected: Self::TestAnswer2) -> Result<()> {
+        let answer = Self::b_test(Self::final_parse_test(data)?)?;
+        check_sample_answer(name, Part::B, answer, expected)
+    }
+
     fn test_a() -> anyhow::Result<()> {
         assert_eq!(
             Self::a_test(Self::final_parse_test(Self::SAMPLE_DATA)?)?,
             Self::SAMPLE_ANSWER_A
         );
-        println!("a: {}", Self::part1(Self::final_parse(Self::DATA)?)?);
-        Ok(())
+        let answer = Self::part1(Self::final_parse(Self::DATA)?)?.to_string();
+        println!("a: {}", answer);
+        Self::verify(Part::A, &answer)
     }
 
     fn test_b() -> anyhow::Result<()> {
@@ -103,35 +180,133 @@ pub trait Solution {
             Self::b_test(Self::final_parse_test(Self::SAMPLE_DATA_B)?)?,
             Self::SAMPLE_ANSWER_B
         );
-        println!("b: {}", Self::part2(Self::final_parse(Self::DATA)?)?);
-        Ok(())
+        let answer = Self::part2(Self::final_parse(Self::DATA)?)?.to_string();
+        println!("b: {}", answer);
+        Self::verify(Part::B, &answer)
     }
 
-    fn main() -> anyhow::Result<()> {
-        let paring_time = Instant::now();
-        let parsed = Self::final_parse(Self::DATA)?;
-        println!("Parsed in {:?}", paring_time.elapsed());
-        let arg = std::env::args().nth(1);
-        match arg.as_deref() {
-            Some("a") => {
-                let now = Instant::now();
-                println!("a: {} ({:?})", Self::part1(parsed)?, now.elapsed());
+    fn main() -> anyhow::Result<()>
+    where
+        Self: Sized + 'static,
+    {
+        let options = Options::from_args()?;
+        options.validate()?;
+        let input = options.load_input(Self::DATA)?;
+        let data = &*input;
+        if options.spans {
+            span::enable();
+        }
+
+        if let Some(iterations) = options.bench {
+            let report = bench::bench::<Self>(data, options.part, iterations)
+                .inspect_err(|error| eprint_diagnostic(error, data))?;
+            print!("{}", report);
+            return Ok(());
+        }
+
+        let (day, variant) = runner::day_of::<Self>();
+        let registration = runner::Registration::new::<Self>(day, variant);
+        let result = match options.cache() {
+            Some(cache) => registration.run_cached(&cache, data, options.part, options.timeout),
+            None => registration.run(data, options.part, options.timeout),
+        };
+
+        if options.format == Format::Json {
+            print!("{}", runner::json_lines(day, variant, &result));
+            let report = result?;
+            if options.verify {
+                report.verify(Self::ANSWERS)?;
             }
-            Some("b") => {
-                let now = Instant::now();
-                println!("b: {} ({:?})", Self::part2(parsed)?, now.elapsed());
+            return report
+                .parts
+                .into_iter()
+                .try_for_each(|part| part.answer.map(drop));
+        }
+
+        let report = result.inspect_err(|error| eprint_diagnostic(error, data))?;
+        let spans = runner::span_tree(&registration.name(), &report);
+        let memory = |memory: Option<memory::AllocStats>| match memory {
+            Some(stats) => format!(", {}", stats),
+            None => String::new(),
+        };
+        if !report.parts.iter().all(|part| part.cached) {
+            println!(
+                "Parsed in {:?}{}",
+                report.parse_time,
+                memory(report.parse_memory)
+            );
+        }
+        for PartReport {
+            part,
+            answer,
+            time,
+            memory: allocated,
+            cached,
+            ..
+        } in report.parts
+        {
+            let label = match (options.part, part) {
+                (Some(_), Part::A) => "a",
+                (Some(_), Part::B) => "b",
+                (None, Part::A) => "part 1",
+                (None, Part::B) => "part 2",
+            };
+            let answer = answer?;
+            match cached {
+                true => println!("{}: {} (cached)", label, answer),
+                false => println!("{}: {} ({:?}{})", label, answer, time, memory(allocated)),
             }
-            _ => {
-                let now_a = Instant::now();
-                println!(
-                    "part 1: {} ({:?})",
-                    Self::part1(parsed.clone())?,
-                    now_a.elapsed()
-                );
-                let now_b = Instant::now();
-                println!("part 2: {} ({:?})", Self::part2(parsed)?, now_b.elapsed());
+            if options.verify {
+                Self::verify(part, &answer)?;
             }
         }
+        print!("{}", spans);
         Ok(())
     }
 }
+
+fn check_sample_answer<A, E>(name: &str, part: Part, answer: A, expected: E) -> Result<()>
+where
+    A: Debug + PartialEq<E>,
+    E: Debug,
+{
+    ensure!(
+        answer == expected,
+        "sample {} (part {:?}): expected {:?}, got {:?}",
+        name,
+        part,
+        expected,
+        answer
+    );
+    Ok(())
+}
+
+/// Declares a table of sample cases for a solution, each running as its own test.
+///
+/// ```ignore
+/// sample_tests! {
+///     Day;
+///     a_sample1: A, include_str!("sample1.txt") => 142;
+///     b_sample2: B, include_str!("sample2.txt") => 281;
+/// }
+/// ```
+#[macro_export]
+macro_rules! sample_tests {
+    (@case $day:ty, $name:ident, A, $data:expr, $expected:expr) => {
+        $crate::sample_tests!(@test $day, $name, check_sample_a, $data, $expected);
+    };
+    (@case $day:ty, $name:ident, B, $data:expr, $expected:expr) => {
+        $crate::sample_tests!(@test $day, $name, check_sample_b, $data, $expected);
+    };
+    (@test $day:ty, $name:ident, $check:ident, $data:expr, $expected:expr) => {
+        #[test]
+        fn $name() -> anyhow::Result<()> {
+            <$day as $crate::Solution>::$check(stringify!($name), $data, $expected)
+        }
+    };
+    ($day:ty; $($name:ident: $part:ident, $data:expr => $expected:expr;)*) => {
+        $(
+            $crate::sample_tests!(@case $day, $name, $part, $data, $expected);
+        )*
+    };
+}
@@ -0,0 +1,165 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Whether the counting allocator is installed, with the `alloc-stats` feature.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// What one phase of a run allocated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// The most memory held at once, above what was held when the phase started.
+    pub peak_bytes: usize,
+    /// Every byte allocated, including ones freed again.
+    pub allocated_bytes: usize,
+    pub allocations: usize,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} peak, {} in {} allocs",
+            Bytes(self.peak_bytes),
+            Bytes(self.allocated_bytes),
+            self.allocations
+        )
+    }
+}
+
+struct Bytes(usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{}B", self.0);
+        }
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{:.1}{}", size, UNITS[unit])
+    }
+}
+
+/// Runs `f`, and counts what it allocates if the counting allocator is installed.
+///
+/// The counts are process-wide, so allocations on other threads land in them too: good for
+/// solvers that fan out with rayon, but measurements mustn't overlap.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let start = counting::snapshot();
+        let result = f();
+        (result, Some(counting::since(start)))
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::AllocStats;
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        sync::atomic::{AtomicUsize, Ordering::Relaxed},
+    };
+
+    static CURRENT: AtomicUsize = AtomicUsize::new(0);
+    static PEAK: AtomicUsize = AtomicUsize::new(0);
+    static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+    static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+    /// The system allocator, counting as it goes. A `realloc` counts as a fresh allocation
+    /// of the new size.
+    struct Counting;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    fn record(size: usize) {
+        let current = CURRENT.fetch_add(size, Relaxed) + size;
+        PEAK.fetch_max(current, Relaxed);
+        ALLOCATED.fetch_add(size, Relaxed);
+        ALLOCATIONS.fetch_add(1, Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            CURRENT.fetch_sub(layout.size(), Relaxed);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new = System.realloc(ptr, layout, new_size);
+            if !new.is_null() {
+                CURRENT.fetch_sub(layout.size(), Relaxed);
+                record(new_size);
+            }
+            new
+        }
+    }
+
+    pub struct Snapshot {
+        current: usize,
+        allocated: usize,
+        allocations: usize,
+    }
+
+    /// Starts a measurement, resetting the peak to what's held now.
+    pub fn snapshot() -> Snapshot {
+        let current = CURRENT.load(Relaxed);
+        PEAK.store(current, Relaxed);
+        Snapshot {
+            current,
+            allocated: ALLOCATED.load(Relaxed),
+            allocations: ALLOCATIONS.load(Relaxed),
+        }
+    }
+
+    pub fn since(start: Snapshot) -> AllocStats {
+        AllocStats {
+            peak_bytes: PEAK.load(Relaxed).saturating_sub(start.current),
+            allocated_bytes: ALLOCATED.load(Relaxed) - start.allocated,
+            allocations: ALLOCATIONS.load(Relaxed) - start.allocations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_only_with_the_feature() {
+        let (vec, stats) = measure(|| vec![0u8; 4096]);
+        assert_eq!(vec.len(), 4096);
+        assert_eq!(stats.is_some(), ENABLED);
+        // Other tests allocate and free at the same time, so only the totals are certain
+        if let Some(stats) = stats {
+            assert!(stats.allocations >= 1);
+            assert!(stats.allocated_bytes >= 4096);
+        }
+        assert_eq!(Bytes(512).to_string(), "512B");
+        assert_eq!(Bytes(3 * 1024 * 1024 / 2).to_string(), "1.5MiB");
+    }
+}
@@ -0,0 +1,238 @@
+use anyhow::*;
+use std::{
+    borrow::Cow,
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+    time::Duration,
+};
+
+use crate::{cache::Cache, memory};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    A,
+    B,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::File(path) => fs::read_to_string(path)
+                .with_context(|| format!("Failed to read input from {}", path.display())),
+            InputSource::Stdin => {
+                let mut data = String::new();
+                io::stdin()
+                    .read_to_string(&mut data)
+                    .context("Failed to read input from stdin")?;
+                Ok(data)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON record per line; see [`crate::runner::json_lines`].
+    Json,
+}
+
+/// Command line options shared by every day's binary.
+///
+/// `a` / `b` select a single part, `--input <path>` reads the puzzle input from a file
+/// instead of the embedded `input.txt`, and `--input -` reads it from stdin.
+/// `--bench <n>` times every phase over `n` runs instead of solving once, and `--verify`
+/// fails the run if an answer differs from the one recorded in `answers.txt`.
+/// `--format json` prints machine-readable records instead of text, and `--threads <n>`
+/// caps how many days the `aoc` runner solves at once. `--timeout <seconds>` gives up on
+/// a part that runs longer than that, and `--spans` breaks each phase's time down into
+/// the [`crate::span`]s it entered. `--cache` looks answers up in the [`Cache`] before
+/// solving, and saves new ones there, unless `--no-cache` bypasses it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    pub part: Option<Part>,
+    pub input: Option<InputSource>,
+    pub bench: Option<usize>,
+    pub verify: bool,
+    pub format: Format,
+    pub threads: Option<usize>,
+    pub timeout: Option<Duration>,
+    pub spans: bool,
+    pub cache_answers: bool,
+    pub no_cache: bool,
+}
+
+impl Options {
+    pub fn from_args() -> Result<Self> {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+                _ => (arg.as_str(), None),
+            };
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| anyhow!("Missing value for {}", flag))
+            };
+
+            match flag {
+                "a" => options.part = Some(Part::A),
+                "b" => options.part = Some(Part::B),
+                "--input" | "-i" => {
+                    options.input = Some(match value()?.as_str() {
+                        "-" => InputSource::Stdin,
+                        path => InputSource::File(path.into()),
+                    })
+                }
+                "--bench" => {
+                    let value = value()?;
+                    let iterations = value.parse().ok().filter(|&n| n > 0);
+                    let err = || anyhow!("Invalid --bench iterations: {}", value);
+                    options.bench = Some(iterations.ok_or_else(err)?);
+                }
+                "--threads" => {
+                    let value = value()?;
+                    let threads = value.parse().ok().filter(|&n| n > 0);
+                    let err = || anyhow!("Invalid --threads count: {}", value);
+                    options.threads = Some(threads.ok_or_else(err)?);
+                }
+                "--timeout" => {
+                    let value = value()?;
+                    let timeout = value.parse().ok().filter(|&s: &f64| s > 0.0);
+                    let timeout = timeout.and_then(|s| Duration::try_from_secs_f64(s).ok());
+                    let err = || anyhow!("Invalid --timeout seconds: {}", value);
+                    options.timeout = Some(timeout.ok_or_else(err)?);
+                }
+                "--verify" => options.verify = true,
+                "--spans" => options.spans = true,
+                "--cache" => options.cache_answers = true,
+                "--no-cache" => options.no_cache = true,
+                "--format" => {
+                    options.format = match value()?.as_str() {
+                        "text" => Format::Text,
+                        "json" => Format::Json,
+                        format => bail!("Unknown --format: {}", format),
+                    }
+                }
+                _ => bail!("Unknown argument: {}", arg),
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Fails on combinations of options that can't run together.
+    pub fn validate(&self) -> Result<()> {
+        ensure!(
+            !self.verify || self.input.is_none(),
+            "Answers are only recorded for the embedded input"
+        );
+        ensure!(
+            self.bench.is_none() || self.format == Format::Text,
+            "--bench only supports --format text"
+        );
+        ensure!(
+            !self.spans || self.format == Format::Text && self.bench.is_none(),
+            "--spans only supports --format text, without --bench"
+        );
+        Ok(())
+    }
+
+    /// Where to look answers up before solving, if `--cache` was given, `--no-cache` wasn't,
+    /// and the run doesn't measure something a cached answer would skip. `--verify` always
+    /// solves, since a cached answer could come from before the change being checked.
+    pub fn cache(&self) -> Option<Cache> {
+        let measuring = self.bench.is_some() || self.spans || memory::ENABLED;
+        let wanted = self.cache_answers && !self.no_cache;
+        (wanted && !measuring && !self.verify).then(Cache::default)
+    }
+
+    /// Returns the puzzle input to run against, falling back to the embedded data.
+    pub fn load_input(&self, embedded: &'static str) -> Result<Cow<'static, str>> {
+        match &self.input {
+            None => Ok(Cow::Borrowed(embedded)),
+            Some(source) => Ok(Cow::Owned(source.read()?)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options> {
+        Options::parse(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn parses_part_and_input() -> Result<()> {
+        let options = parse(&["b", "--input", "other.txt", "--threads=4"])?;
+        assert_eq!(options.part, Some(Part::B));
+        assert_eq!(options.input, Some(InputSource::File("other.txt".into())));
+        assert_eq!(options.threads, Some(4));
+        assert_eq!(options.timeout, None);
+
+        let options = parse(&["--input=-", "--bench", "50", "--verify", "--format", "json"])?;
+        assert_eq!(options.part, None);
+        assert_eq!(options.format, Format::Json);
+        assert!(options.verify);
+        assert_eq!(options.input, Some(InputSource::Stdin));
+        assert_eq!(options.bench, Some(50));
+        assert_eq!(options.threads, None);
+
+        let options = parse(&["--timeout", "1.5", "--spans"])?;
+        assert_eq!(options.timeout, Some(Duration::from_millis(1500)));
+        assert!(options.spans);
+        assert_eq!(options.cache(), None);
+
+        assert_eq!(parse(&[])?.cache(), None);
+        let options = parse(&["--cache"])?;
+        assert!(options.cache_answers);
+        assert_eq!(options.cache(), Some(Cache::default()));
+        assert_eq!(parse(&["--cache", "--verify"])?.cache(), None);
+        let options = parse(&["--cache", "--no-cache"])?;
+        assert!(options.no_cache);
+        assert_eq!(options.cache(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_bad_arguments() -> Result<()> {
+        assert!(parse(&["c"]).is_err());
+        assert!(parse(&["--input"]).is_err());
+        assert!(parse(&["--bench", "0"]).is_err());
+        assert!(parse(&["--format=yaml"]).is_err());
+        assert!(parse(&["--threads", "none"]).is_err());
+        assert!(parse(&["--timeout", "-1"]).is_err());
+
+        assert!(parse(&["--verify", "--bench", "5"])?.validate().is_ok());
+        assert!(parse(&["--verify", "--input", "-"])?.validate().is_err());
+        assert!(parse(&["--bench", "5", "--format", "json"])?
+            .validate()
+            .is_err());
+        assert!(parse(&["--spans", "--bench", "5"])?.validate().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn defaults_to_embedded_input() -> Result<()> {
+        assert_eq!(parse(&[])?.load_input("embedded")?, "embedded");
+        Ok(())
+    }
+}
@@ -0,0 +1,645 @@
+use anyhow::*;
+use fastrand::Rng;
+use rayon::prelude::*;
+use std::{
+    any::Any,
+    fmt::Write,
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers,
+    bench::{self, BenchReport},
+    cache::Cache,
+    cancel::{self, CancellationToken},
+    memory::{self, AllocStats},
+    options::Part,
+    span::{self, Span},
+    Solution,
+};
+
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Result<String>,
+    pub time: Duration,
+    /// What solving allocated, with the `alloc-stats` feature.
+    pub memory: Option<AllocStats>,
+    /// The phases solving went through, once [`span::enable`]d.
+    pub spans: Vec<Span>,
+    /// Whether the answer came from a [`Cache`] instead, taking no time.
+    pub cached: bool,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub parse_time: Duration,
+    pub parse_memory: Option<AllocStats>,
+    pub parse_spans: Vec<Span>,
+    pub parts: Vec<PartReport>,
+}
+
+impl Report {
+    /// Fails if any part's answer differs from the ones recorded in `answers`, or if a part
+    /// has no answer at all because it failed, panicked or timed out.
+    pub fn verify(&self, answers: &str) -> Result<()> {
+        for PartReport { part, answer, .. } in &self.parts {
+            match answer {
+                Result::Ok(answer) => answers::verify(answers, *part, answer)?,
+                Err(error) => bail!("part {:?} has no answer: {}", part, error),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Turns a panic in `f` into an error, so one broken solution can't take down the run.
+fn isolate<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| Err(panic_error(payload)))
+}
+
+fn panic_error(payload: Box<dyn Any + Send>) -> Error {
+    let message = match payload.downcast::<String>() {
+        Result::Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Result::Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    };
+    anyhow!("panicked: {}", message)
+}
+
+/// Parses `data` and runs the requested parts (or both) against it.
+///
+/// Parsing and each part run in isolation: a panic becomes that phase's error. With a
+/// `timeout`, each part runs on its own thread and is abandoned once the timeout passes,
+/// after cancelling its [`cancel::current`] token; a solver that never polls the token keeps
+/// its thread busy until the process exits. Such a thread parses its own copy of `data`, and
+/// that parse counts towards the part's time and timeout.
+pub fn run<S: Solution + 'static>(
+    data: &str,
+    part: Option<Part>,
+    timeout: Option<Duration>,
+) -> Result<Report> {
+    let now = Instant::now();
+    let ((parsed, parse_memory), parse_spans) =
+        span::record(|| memory::measure(|| isolate(|| S::final_parse(data))));
+    let parse_time = now.elapsed();
+    let parsed = parsed?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![Part::A, Part::B],
+    };
+
+    let parts = parts
+        .into_iter()
+        .map(|part| {
+            let (answer, time, memory, spans) = match timeout {
+                None => solve::<S>(parsed.clone(), part),
+                Some(timeout) => solve_detached::<S>(data.to_string(), part, timeout),
+            };
+            PartReport {
+                part,
+                answer,
+                time,
+                memory,
+                spans,
+                cached: false,
+            }
+        })
+        .collect();
+
+    Ok(Report {
+        parse_time,
+        parse_memory,
+        parse_spans,
+        parts,
+    })
+}
+
+type Solved = (Result<String>, Duration, Option<AllocStats>, Vec<Span>);
+
+fn solve<S: Solution>(parsed: S::Parsed<'_>, part: Part) -> Solved {
+    let now = Instant::now();
+    let ((answer, memory), spans) = span::record(|| {
+        memory::measure(|| {
+            isolate(|| match part {
+                Part::A => S::part1(parsed).map(|answer| answer.to_string()),
+                Part::B => S::part2(parsed).map(|answer| answer.to_string()),
+            })
+        })
+    });
+    (answer, now.elapsed(), memory, spans)
+}
+
+/// Solves `part` on a thread of its own, which may outlive the run if it times out. So it
+/// gets its own copy of the input to parse, instead of borrowing the caller's, and the time
+/// reported includes that parse.
+fn solve_detached<S: Solution + 'static>(data: String, part: Part, timeout: Duration) -> Solved {
+    let token = CancellationToken::new();
+    let (sender, receiver) = mpsc::channel();
+    let solver_token = token.clone();
+    thread::spawn(move || {
+        let now = Instant::now();
+        let result = cancel::with_token(solver_token, || match isolate(|| S::final_parse(&data)) {
+            Result::Ok(parsed) => solve::<S>(parsed, part),
+            Err(error) => (Err(error), Duration::ZERO, None, vec![]),
+        });
+        let (answer, _, memory, spans) = result;
+        sender.send((answer, now.elapsed(), memory, spans))
+    });
+
+    receiver.recv_timeout(timeout).unwrap_or_else(|error| {
+        token.cancel();
+        let error = match error {
+            RecvTimeoutError::Timeout => anyhow!("timed out after {:?}", timeout),
+            RecvTimeoutError::Disconnected => anyhow!("solver thread exited without an answer"),
+        };
+        (Err(error), timeout, None, vec![])
+    })
+}
+
+/// A type-erased `Solution`, so the runner can hold every day in one list.
+#[derive(Debug, Clone, Copy)]
+pub struct Registration {
+    pub day: u8,
+    pub variant: &'static str,
+    pub data: &'static str,
+    pub samples: [&'static str; 2],
+    pub answers: &'static str,
+    pub version: &'static str,
+    runner: fn(&str, Option<Part>, Option<Duration>) -> Result<Report>,
+    bencher: fn(&str, Option<Part>, usize) -> Result<BenchReport>,
+    generator: fn(&mut Rng, usize) -> Option<String>,
+}
+
+impl Registration {
+    pub fn new<S: Solution + 'static>(day: u8, variant: &'static str) -> Self {
+        Self {
+            day,
+            variant,
+            data: S::DATA,
+            samples: [S::SAMPLE_DATA, S::SAMPLE_DATA_B],
+            answers: S::ANSWERS,
+            version: S::VERSION,
+            runner: run::<S>,
+            bencher: bench::bench::<S>,
+            generator: S::generate,
+        }
+    }
+
+    /// `day07`, or `day14_better` for alternate implementations.
+    pub fn name(&self) -> String {
+        name(self.day, self.variant)
+    }
+
+    pub fn run(&self, data: &str, part: Option<Part>, timeout: Option<Duration>) -> Result<Report> {
+        (self.runner)(data, part, timeout)
+    }
+
+    /// Like [`Registration::run`], but takes the answers `cache` has for `data` instead of
+    /// solving those parts, and caches the ones it solves. Parsing is skipped if every part
+    /// was cached.
+    pub fn run_cached(
+        &self,
+        cache: &Cache,
+        data: &str,
+        part: Option<Part>,
+        timeout: Option<Duration>,
+    ) -> Result<Report> {
+        let parts = match part {
+            Some(part) => vec![part],
+            None => vec![Part::A, Part::B],
+        };
+        let (cached, missing): (Vec<_>, Vec<_>) = parts
+            .into_iter()
+            .map(|part| (part, cache.get(self, part, data)))
+            .partition(|(_, answer)| answer.is_some());
+
+        let mut report = match missing.as_slice() {
+            [] => Report {
+                parse_time: Duration::ZERO,
+                parse_memory: None,
+                parse_spans: vec![],
+                parts: vec![],
+            },
+            [(part, _)] => self.run(data, Some(*part), timeout)?,
+            _ => self.run(data, None, timeout)?,
+        };
+        for solved in &report.parts {
+            if let Result::Ok(answer) = &solved.answer {
+                // A cache that can't be written to only means solving again next time
+                cache.put(self, solved.part, data, answer).ok();
+            }
+        }
+
+        report
+            .parts
+            .extend(cached.into_iter().map(|(part, answer)| PartReport {
+                part,
+                answer: Result::Ok(answer.unwrap_or_default()),
+                time: Duration::ZERO,
+                memory: None,
+                spans: vec![],
+                cached: true,
+            }));
+        report.parts.sort_by_key(|report| report.part == Part::B);
+        Ok(report)
+    }
+
+    pub fn bench(&self, data: &str, part: Option<Part>, iterations: usize) -> Result<BenchReport> {
+        (self.bencher)(data, part, iterations)
+    }
+
+    pub fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        (self.generator)(rng, size)
+    }
+}
+
+/// Runs each registration against its input concurrently, on a pool of `threads` threads
+/// (one per core if `None`), returning the results in the order given. With a `cache`,
+/// parts it has answers for aren't solved again.
+///
+/// With the counting allocator installed they run one at a time instead, so each run's
+/// [`memory::measure`]s only see its own allocations.
+pub fn run_parallel<'a>(
+    runs: &[(&'a Registration, &str)],
+    part: Option<Part>,
+    timeout: Option<Duration>,
+    threads: Option<usize>,
+    cache: Option<&Cache>,
+) -> Result<Vec<(&'a Registration, Result<Report>)>> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads.unwrap_or(0))
+        .build()?;
+
+    let run = |&(registration, data): &(&'a Registration, &str)| {
+        let report = match cache {
+            None => registration.run(data, part, timeout),
+            Some(cache) => registration.run_cached(cache, data, part, timeout),
+        };
+        (registration, report)
+    };
+    Ok(match memory::ENABLED {
+        true => pool.install(|| runs.iter().map(run).collect()),
+        false => pool.install(|| runs.par_iter().map(run).collect()),
+    })
+}
+
+/// Selects registrations by `all`, `7`, `07`, `day07`, `14_better` or `day14_better`.
+///
+/// A day without a variant selects every variant of that day.
+pub fn select<'a>(registry: &'a [Registration], spec: &str) -> Result<Vec<&'a Registration>> {
+    if spec == "all" {
+        return Ok(registry.iter().collect());
+    }
+
+    let spec = spec.strip_prefix("day").unwrap_or(spec);
+    let (day, variant) = match spec.split_once('_') {
+        Some((day, variant)) => (day, Some(variant)),
+        None => (spec, None),
+    };
+    let day: u8 = day.parse().map_err(|_| anyhow!("Invalid day: {}", spec))?;
+
+    let selected = registry
+        .iter()
+        .filter(|registration| registration.day == day)
+        .filter(|registration| variant.is_none_or(|v| registration.variant == v))
+        .collect::<Vec<_>>();
+
+    ensure!(!selected.is_empty(), "No solution registered for {}", spec);
+    Ok(selected)
+}
+
+/// Renders one row per run: the answers and timings of each part, plus a total. With the
+/// counting allocator installed, each timing is followed by what that phase allocated.
+/// Cached answers show as such in place of a timing.
+pub fn summary_table(results: &[(&Registration, Result<Report>)]) -> String {
+    let mut header = vec!["day".to_string()];
+    for column in ["parse", "part 1", "part 2"] {
+        header.push(column.to_string());
+        if column != "parse" {
+            header.push("time".to_string());
+        }
+        if memory::ENABLED {
+            header.push("memory".to_string());
+        }
+    }
+    let columns = header.len();
+    let columns_per_part = if memory::ENABLED { 3 } else { 2 };
+    let mut rows = vec![header];
+    let mut total = Duration::ZERO;
+
+    let memory_cell = |memory: &Option<AllocStats>| memory.map(|stats| stats.to_string());
+    for (registration, result) in results {
+        let mut row = vec![registration.name()];
+        match result {
+            Err(error) => row.push(format!("error: {}", error)),
+            Result::Ok(report) => {
+                total += report.parse_time;
+                row.push(match report.parts.iter().all(|part| part.cached) {
+                    true => "-".to_string(),
+                    false => format!("{:?}", report.parse_time),
+                });
+                row.extend(memory_cell(&report.parse_memory));
+                for part in [Part::A, Part::B] {
+                    match report.parts.iter().find(|p| p.part == part) {
+                        None => {
+                            row.push("-".to_string());
+                            row.resize(row.len() + columns_per_part - 1, String::new());
+                        }
+                        Some(PartReport {
+                            answer,
+                            time,
+                            memory,
+                            cached,
+                            ..
+                        }) => {
+                            total += *time;
+                            row.push(match answer {
+                                Result::Ok(answer) => answer.clone(),
+                                Err(error) => format!("error: {}", error),
+                            });
+                            row.push(match cached {
+                                true => "cached".to_string(),
+                                false => format!("{:?}", time),
+                            });
+                            row.extend(memory_cell(memory));
+                        }
+                    }
+                }
+            }
+        }
+        row.resize(columns, String::new());
+        rows.push(row);
+    }
+
+    let widths = (0..rows[0].len())
+        .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    let mut table = String::new();
+    for row in &rows {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>();
+        writeln!(table, "{}", cells.join("  ").trim_end()).unwrap();
+    }
+    writeln!(table, "total: {:?}", total).unwrap();
+    table
+}
+
+/// Renders the [`Span`]s a run recorded, as a tree under a heading per phase. Phases that
+/// entered no spans are left out, so this is empty unless spans were enabled.
+pub fn span_tree(name: &str, report: &Report) -> String {
+    let mut tree = String::new();
+    let phases = [("parse", &report.parse_spans)]
+        .into_iter()
+        .chain(report.parts.iter().map(|part| match part.part {
+            Part::A => ("part 1", &part.spans),
+            Part::B => ("part 2", &part.spans),
+        }));
+    for (phase, spans) in phases.filter(|(_, spans)| !spans.is_empty()) {
+        writeln!(tree, "{} {}", name, phase).unwrap();
+        tree += &span::render(spans, 1);
+    }
+    tree
+}
+
+/// Renders runs as JSON Lines: one record per part, or a single `error` record if parsing
+/// failed. Times are in nanoseconds; allocation counts, when measured, are in bytes. Cached
+/// answers are marked `"cached":true`, and took no time.
+pub fn json_lines(day: u8, variant: &str, result: &Result<Report>) -> String {
+    let mut lines = String::new();
+    let key = format!("\"day\":{},\"variant\":{}", day, json_string(variant));
+
+    match result {
+        Err(error) => {
+            let error = json_string(&error.to_string());
+            writeln!(lines, "{{{},\"error\":{}}}", key, error).unwrap();
+        }
+        Result::Ok(report) => {
+            for PartReport {
+                part,
+                answer,
+                time,
+                memory,
+                cached,
+                ..
+            } in &report.parts
+            {
+                let part = match part {
+                    Part::A => 1,
+                    Part::B => 2,
+                };
+                let outcome = match answer {
+                    Result::Ok(answer) => format!("\"answer\":{}", json_string(answer)),
+                    Err(error) => format!("\"error\":{}", json_string(&error.to_string())),
+                };
+                writeln!(
+                    lines,
+                    "{{{},\"part\":{},{},\"parse_ns\":{},\"solve_ns\":{}{}{}{}}}",
+                    key,
+                    part,
+                    outcome,
+                    report.parse_time.as_nanos(),
+                    time.as_nanos(),
+                    json_memory("parse_", &report.parse_memory),
+                    json_memory("", memory),
+                    if *cached { ",\"cached\":true" } else { "" }
+                )
+                .unwrap();
+            }
+        }
+    }
+    lines
+}
+
+/// `,"<prefix>peak_bytes":...` and the other counts, if they were measured.
+fn json_memory(prefix: &str, memory: &Option<AllocStats>) -> String {
+    let Some(stats) = memory else {
+        return String::new();
+    };
+    format!(
+        ",\"{0}peak_bytes\":{1},\"{0}allocated_bytes\":{2},\"{0}allocations\":{3}",
+        prefix, stats.peak_bytes, stats.allocated_bytes, stats.allocations
+    )
+}
+
+fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// How the registry names a day and variant: `day07`, or `day14_better`.
+pub fn name(day: u8, variant: &str) -> String {
+    match variant {
+        "" => format!("day{:02}", day),
+        variant => format!("day{:02}_{}", day, variant),
+    }
+}
+
+/// The day and variant of a solution, read off its module path the way the registry names
+/// them: `day14_better::Day` is day 14, variant `better`.
+pub fn day_of<S>() -> (u8, &'static str) {
+    std::any::type_name::<S>()
+        .split("::")
+        .find_map(|segment| {
+            let segment = segment.strip_prefix("day")?;
+            let (day, variant) = segment.split_once('_').unwrap_or((segment, ""));
+            Some((day.parse().ok()?, variant))
+        })
+        .unwrap_or((0, ""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_records() {
+        let report = Report {
+            parse_time: Duration::from_nanos(1500),
+            parse_memory: None,
+            parse_spans: vec![],
+            parts: vec![
+                PartReport {
+                    part: Part::A,
+                    answer: Result::Ok("42".to_string()),
+                    time: Duration::from_nanos(20),
+                    memory: Some(AllocStats {
+                        peak_bytes: 64,
+                        allocated_bytes: 96,
+                        allocations: 2,
+                    }),
+                    spans: vec![],
+                    cached: true,
+                },
+                PartReport {
+                    part: Part::B,
+                    answer: Err(anyhow!("no \"path\"")),
+                    time: Duration::from_nanos(3),
+                    memory: None,
+                    spans: vec![],
+                    cached: false,
+                },
+            ],
+        };
+
+        assert_eq!(
+            json_lines(14, "better", &Result::Ok(report)),
+            "{\"day\":14,\"variant\":\"better\",\"part\":1,\"answer\":\"42\",\"parse_ns\":1500,\"solve_ns\":20,\"peak_bytes\":64,\"allocated_bytes\":96,\"allocations\":2,\"cached\":true}\n\
+             {\"day\":14,\"variant\":\"better\",\"part\":2,\"error\":\"no \\\"path\\\"\",\"parse_ns\":1500,\"solve_ns\":3}\n"
+        );
+        assert_eq!(
+            json_lines(3, "", &Err(anyhow!("bad\ninput"))),
+            "{\"day\":3,\"variant\":\"\",\"error\":\"bad\\ninput\"}\n"
+        );
+    }
+
+    struct Panicky;
+
+    impl crate::BasicSolution for Panicky {
+        type Answer = usize;
+        const DATA: &'static str = "";
+        const SAMPLE_DATA: &'static str = "";
+        const SAMPLE_ANSWER_A: usize = 0;
+        const SAMPLE_ANSWER_B: usize = 0;
+
+        fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+            Ok(data)
+        }
+
+        fn part1(_: Self::Parsed<'_>) -> Result<usize> {
+            panic!("boom")
+        }
+
+        fn part2(data: Self::Parsed<'_>) -> Result<usize> {
+            Ok(data.len())
+        }
+    }
+
+    #[test]
+    fn panics_are_isolated_per_part() -> Result<()> {
+        let report = run::<Panicky>("abc", None, None)?;
+        let answers = report
+            .parts
+            .iter()
+            .map(|part| match &part.answer {
+                Result::Ok(answer) => answer.clone(),
+                Err(error) => error.to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(answers, ["panicked: boom", "3"]);
+        let error = report.verify("").unwrap_err();
+        assert_eq!(error.to_string(), "part A has no answer: panicked: boom");
+        Ok(())
+    }
+
+    struct Stuck;
+
+    impl crate::BasicSolution for Stuck {
+        type Answer = usize;
+        const DATA: &'static str = "";
+        const SAMPLE_DATA: &'static str = "";
+        const SAMPLE_ANSWER_A: usize = 0;
+        const SAMPLE_ANSWER_B: usize = 0;
+
+        fn parse(data: &str) -> Result<Self::Parsed<'_>> {
+            Ok(data)
+        }
+
+        fn part1(_: Self::Parsed<'_>) -> Result<usize> {
+            let token = cancel::current();
+            loop {
+                token.check()?;
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+
+        fn part2(data: Self::Parsed<'_>) -> Result<usize> {
+            Ok(data.len())
+        }
+    }
+
+    #[test]
+    fn parts_time_out() -> Result<()> {
+        let timeout = Duration::from_millis(50);
+        let report = run::<Stuck>("abc", None, Some(timeout))?;
+
+        let error = report.parts[0].answer.as_ref().unwrap_err();
+        assert_eq!(error.to_string(), "timed out after 50ms");
+        assert_eq!(report.parts[1].answer.as_ref().ok(), Some(&"3".to_string()));
+        assert!(report.verify("").is_err());
+        Ok(())
+    }
+
+    mod day14_better {
+        pub struct Day;
+    }
+
+    #[test]
+    fn day_from_module_path() {
+        assert_eq!(day_of::<day14_better::Day>(), (14, "better"));
+        assert_eq!(day_of::<Report>(), (0, ""));
+    }
+}
@@ -0,0 +1,157 @@
+use anyhow::*;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const TEMPLATE: &str = include_str!("days/template/mod.rs");
+
+/// Where `aoc new` registers days in `src/days/mod.rs`: new lines go right above the marker.
+pub const DAYS_MARKER: &str = "// `aoc new` adds days above this line";
+pub const REGISTRY_MARKER: &str = "// `aoc new` registers days above this line";
+
+/// Creates `src/days/dayNN` under `root` from the template, with an optional (empty) domain
+/// module, plus its `src/bin/dayNN` binary, and registers it with the runner. Returns the
+/// files it wrote.
+///
+/// Fails without touching anything if the day already exists.
+pub fn new_day(root: &Path, day: u8, module: Option<&str>) -> Result<Vec<PathBuf>> {
+    ensure!((1..=25).contains(&day), "No puzzle for day {}", day);
+    if let Some(module) = module {
+        ensure!(is_identifier(module), "Invalid module name: {}", module);
+    }
+
+    let name = format!("day{:02}", day);
+    let dir = root.join("src/days").join(&name);
+    let bin_dir = root.join("src/bin").join(&name);
+    for dir in [&dir, &bin_dir] {
+        ensure!(!dir.exists(), "{} already exists", dir.display());
+    }
+
+    let days_path = root.join("src/days/mod.rs");
+    let days = register(
+        &fs::read_to_string(&days_path)?,
+        DAYS_MARKER,
+        &format!("pub mod {};", name),
+    )?;
+    let days = register(
+        &days,
+        REGISTRY_MARKER,
+        &format!("Registration::new::<{}::Day>({}, \"\"),", name, day),
+    )?;
+
+    let mut files = vec![
+        (dir.join("mod.rs"), day_source(module)),
+        (dir.join("input.txt"), String::new()),
+        (dir.join("sample.txt"), String::new()),
+        (dir.join("answers.txt"), String::new()),
+    ];
+    if let Some(module) = module {
+        files.push((dir.join(format!("{}.rs", module)), String::new()));
+    }
+    files.push((bin_dir.join("main.rs"), bin_source(&name)));
+
+    for (path, contents) in &files {
+        fs::create_dir_all(path.parent().unwrap_or(root))?;
+        fs::write(path, contents)?;
+    }
+    fs::write(&days_path, days)?;
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// The template's `main.rs`, declaring `module` if there is one.
+pub fn day_source(module: Option<&str>) -> String {
+    match module {
+        None => TEMPLATE.to_string(),
+        Some(module) => TEMPLATE.replacen(
+            "pub struct Day;\n",
+            &format!("pub struct Day;\n\npub mod {};\n", module),
+            1,
+        ),
+    }
+}
+
+/// The day's binary, a thin wrapper around its library module.
+pub fn bin_source(name: &str) -> String {
+    format!(
+        "use aoc_2023::{{days::{}::Day, Solution}};\n\nfn main() -> anyhow::Result<()> {{\n    Day::main()\n}}\n",
+        name
+    )
+}
+
+/// Inserts `entry` on its own lines above `marker`, at the marker's indentation.
+pub fn register(source: &str, marker: &str, entry: &str) -> Result<String> {
+    let marker_start = source
+        .find(marker)
+        .ok_or_else(|| anyhow!("Missing registration marker: {}", marker))?;
+    let line_start = source[..marker_start].rfind('\n').map_or(0, |i| i + 1);
+    let indent = &source[line_start..marker_start];
+
+    let mut registered = source[..line_start].to_string();
+    for line in entry.lines() {
+        registered.push_str(indent);
+        registered.push_str(line);
+        registered.push('\n');
+    }
+    registered.push_str(&source[line_start..]);
+    Ok(registered)
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_above_marker() -> Result<()> {
+        let source = "vec![\n    a,\n    // marker\n]\n";
+        assert_eq!(
+            register(source, "// marker", "b,")?,
+            "vec![\n    a,\n    b,\n    // marker\n]\n"
+        );
+        assert!(register(source, "// missing", "b,").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn declares_module() {
+        assert_eq!(day_source(None), TEMPLATE);
+        assert!(day_source(Some("garden")).contains("pub struct Day;\n\npub mod garden;\n"));
+    }
+
+    #[test]
+    fn scaffolds_and_refuses_to_overwrite() -> Result<()> {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/days"))?;
+        fs::write(
+            root.join("src/days/mod.rs"),
+            format!(
+                "{}\n    vec![\n        {}\n    ]\n",
+                DAYS_MARKER, REGISTRY_MARKER
+            ),
+        )?;
+
+        let files = new_day(&root, 25, Some("snow"))?;
+        assert_eq!(files.len(), 6);
+        assert!(root.join("src/days/day25/snow.rs").exists());
+        assert!(root.join("src/bin/day25/main.rs").exists());
+        let days = fs::read_to_string(root.join("src/days/mod.rs"))?;
+        assert!(days.starts_with("pub mod day25;\n"));
+        assert!(days.contains("        Registration::new::<day25::Day>(25, \"\"),\n"));
+
+        assert!(new_day(&root, 25, None).is_err());
+        assert!(new_day(&root, 26, None).is_err());
+        assert!(new_day(&root, 1, Some("Snow")).is_err());
+
+        fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+use std::{
+    cell::RefCell,
+    fmt::Write,
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+/// A named phase of a solution, how long it took, and the phases inside it. Spans entered
+/// more than once under the same parent are merged, adding up their time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub name: &'static str,
+    pub time: Duration,
+    pub count: usize,
+    pub children: Vec<Span>,
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turns on recording for every [`record`] from now on; until then spans cost a flag check.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+struct Frame {
+    name: &'static str,
+    start: Instant,
+    children: Vec<Span>,
+}
+
+thread_local! {
+    /// The spans open on this thread, innermost last, while a [`record`] is running.
+    static STACK: RefCell<Option<Vec<Frame>>> = const { RefCell::new(None) };
+}
+
+/// Marks a phase that lasts until the returned guard is dropped:
+///
+/// ```ignore
+/// let _span = span::enter("settle bricks");
+/// ```
+///
+/// Only spans entered on the thread running a [`record`] are kept, so work handed to other
+/// threads (rayon's, say) isn't broken down.
+pub fn enter(name: &'static str) -> Guard {
+    let recording = ENABLED.load(Ordering::Relaxed)
+        && STACK.with(|stack| match &mut *stack.borrow_mut() {
+            Some(frames) => {
+                frames.push(Frame {
+                    name,
+                    start: Instant::now(),
+                    children: vec![],
+                });
+                true
+            }
+            None => false,
+        });
+    Guard {
+        recording,
+        _not_send: PhantomData,
+    }
+}
+
+/// Closes the span it was entered for when dropped.
+#[must_use = "the span closes as soon as the guard is dropped"]
+pub struct Guard {
+    recording: bool,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        if !self.recording {
+            return;
+        }
+        STACK.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            let Some(frames) = stack.as_mut() else {
+                return;
+            };
+            let frame = frames
+                .pop()
+                .expect("a span closed more often than it opened");
+            let span = Span {
+                name: frame.name,
+                time: frame.start.elapsed(),
+                count: 1,
+                children: frame.children,
+            };
+            let parent = frames
+                .last_mut()
+                .expect("the recording's own frame stays open");
+            merge(&mut parent.children, span);
+        });
+    }
+}
+
+fn merge(spans: &mut Vec<Span>, span: Span) {
+    match spans.iter_mut().find(|existing| existing.name == span.name) {
+        None => spans.push(span),
+        Some(existing) => {
+            existing.time += span.time;
+            existing.count += span.count;
+            for child in span.children {
+                merge(&mut existing.children, child);
+            }
+        }
+    }
+}
+
+/// Runs `f`, collecting the spans it enters on this thread if recording is enabled.
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, Vec<Span>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return (f(), vec![]);
+    }
+
+    struct Restore(Option<Vec<Frame>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            STACK.with(|stack| *stack.borrow_mut() = self.0.take());
+        }
+    }
+
+    let root = Frame {
+        name: "",
+        start: Instant::now(),
+        children: vec![],
+    };
+    let previous = STACK.with(|stack| stack.replace(Some(vec![root])));
+    let restore = Restore(previous);
+    let result = f();
+    let frames = STACK.with(|stack| stack.replace(None));
+    drop(restore);
+
+    let spans = frames
+        .and_then(|mut frames| frames.drain(..).next())
+        .map(|root| root.children)
+        .unwrap_or_default();
+    (result, spans)
+}
+
+/// One line per span, indented under its parent, with the time and how often it ran.
+pub fn render(spans: &[Span], depth: usize) -> String {
+    let mut tree = String::new();
+    for span in spans {
+        write!(
+            tree,
+            "{:indent$}{} {:?}",
+            "",
+            span.name,
+            span.time,
+            indent = 2 * depth
+        )
+        .unwrap();
+        if span.count > 1 {
+            write!(tree, " ({}×)", span.count).unwrap();
+        }
+        tree.push('\n');
+        tree += &render(&span.children, depth + 1);
+    }
+    tree
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_and_merged() {
+        enable();
+        let ((), spans) = record(|| {
+            let _outer = enter("find cycle");
+            for _ in 0..3 {
+                let _inner = enter("spin");
+            }
+        });
+
+        assert_eq!(spans.len(), 1);
+        assert_eq!((spans[0].name, spans[0].count), ("find cycle", 1));
+        let inner = &spans[0].children;
+        assert_eq!((inner[0].name, inner[0].count), ("spin", 3));
+
+        let tree = render(&spans, 0);
+        assert!(tree.starts_with("find cycle "));
+        assert!(tree.lines().nth(1).unwrap().starts_with("  spin "));
+        assert!(tree.ends_with("(3×)\n"));
+
+        // Outside a recording, spans are dropped
+        drop(enter("nowhere"));
+        assert!(record(|| ()).1.is_empty());
+    }
+}
@@ -0,0 +1,75 @@
+use fastrand::Rng;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+/// A `width` by `height` grid with a `tile` in every cell, one line per row.
+pub fn grid(
+    rng: &mut Rng,
+    width: usize,
+    height: usize,
+    mut tile: impl FnMut(&mut Rng) -> char,
+) -> String {
+    (0..height)
+        .map(|_| (0..width).map(|_| tile(rng)).collect::<String>())
+        .join("\n")
+}
+
+/// One of `choices`, picked with a chance proportional to its weight.
+pub fn weighted<T: Copy>(rng: &mut Rng, choices: &[(T, u32)]) -> T {
+    let mut roll = rng.u32(0..choices.iter().map(|(_, weight)| weight).sum::<u32>());
+    for &(choice, weight) in choices {
+        if roll < weight {
+            return choice;
+        }
+        roll -= weight;
+    }
+    unreachable!("the roll is below the total weight")
+}
+
+/// `count` distinct words of `len` letters drawn with `letter`, none of them in `reserved`.
+///
+/// Panics if there aren't that many such words.
+pub fn words(
+    rng: &mut Rng,
+    count: usize,
+    len: usize,
+    mut letter: impl FnMut(&mut Rng) -> char,
+    reserved: &[&str],
+) -> Vec<String> {
+    let mut seen: HashSet<String> = reserved.iter().map(|word| word.to_string()).collect();
+    let mut words = vec![];
+    for _ in 0..count * 1000 {
+        if words.len() == count {
+            break;
+        }
+        let word: String = (0..len).map(|_| letter(rng)).collect();
+        if seen.insert(word.clone()) {
+            words.push(word);
+        }
+    }
+    assert_eq!(
+        words.len(),
+        count,
+        "Couldn't find {} distinct words of {} letters",
+        count,
+        len
+    );
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_and_distinct() {
+        let words = words(&mut Rng::with_seed(7), 20, 2, Rng::lowercase, &["in"]);
+        assert_eq!(words.iter().unique().count(), 20);
+        assert!(!words.contains(&"in".to_string()));
+        assert!(super::words(&mut Rng::with_seed(7), 0, 2, Rng::lowercase, &[]).is_empty());
+
+        let grid = |seed| grid(&mut Rng::with_seed(seed), 3, 2, |rng| rng.digit(10));
+        assert_eq!(grid(1), grid(1));
+        assert_eq!(grid(1).lines().map(str::len).collect_vec(), [3, 3]);
+    }
+}
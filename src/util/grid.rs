@@ -3,9 +3,9 @@ use std::{
     ops::{Index, IndexMut},
 };
 
-use itertools::Itertools;
+use anyhow::{anyhow, Result};
 
-use crate::util::point::*;
+use crate::util::{parse::Source, point::*};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
@@ -14,67 +14,303 @@ pub struct Grid<T> {
     pub data: Vec<T>,
 }
 
+/// How a cell is drawn when its grid is displayed.
+pub trait Render {
+    fn render(&self) -> char;
+}
+
+impl Render for u8 {
+    fn render(&self) -> char {
+        *self as char
+    }
+}
+
+impl Render for char {
+    fn render(&self) -> char {
+        *self
+    }
+}
+
+/// What [`Grid::neighbors`] does with neighbours past the edge of the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edges {
+    /// Leaves them out.
+    Clip,
+    /// Wraps them around to the opposite edge, as if the grid were a torus.
+    Wrap,
+}
+
 impl Grid<u8> {
-    pub fn parse(input: &str) -> Self {
-        let raw = input.lines().map(str::as_bytes).collect_vec();
-        let height = raw.len() as i32;
-        let width = raw[0].len() as i32;
-        let mut data = Vec::with_capacity((width * height) as usize);
-        raw.iter().for_each(|row| data.extend_from_slice(row));
+    /// A grid of the input's bytes, as-is.
+    pub fn parse(input: &str) -> Result<Self> {
+        Self::parse_with(input, Ok)
+    }
+}
 
-        Self {
-            width,
+impl<T> Grid<T> {
+    /// Parses a grid with one cell per byte of each line, turning bytes into cells with
+    /// `cell`. A byte it rejects is reported as a [`ParseError`] at its line and column,
+    /// expecting whatever `cell`'s error says. Every line must be as wide as the first, and
+    /// none may be empty.
+    pub fn parse_with(input: &str, mut cell: impl FnMut(u8) -> Result<T>) -> Result<Self> {
+        let source = Source(input);
+        let mut width = None;
+        let mut height = 0;
+        let mut data = vec![];
+        for line in input.lines() {
+            if line.is_empty() {
+                return Err(source.error(line, "a row of cells").into());
+            }
+            let width = *width.get_or_insert(line.len());
+            if line.len() != width {
+                return Err(source.error(line, format!("a row {} wide", width)).into());
+            }
+            for (x, &byte) in line.as_bytes().iter().enumerate() {
+                let parsed = cell(byte)
+                    .map_err(|error| source.error(char_at(line, x), error.to_string()))?;
+                data.push(parsed);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0) as i32,
             height,
             data,
-        }
+        })
     }
 
-    pub fn iter(&self) -> GridIterator<'_, u8> {
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> GridIterator<'_, T> {
         GridIterator {
             grid: self,
             index: Point::new(0, 0),
         }
     }
 
-    pub fn find(&self, value: u8) -> Option<Point> {
-        let i = self.data.iter().position(|&v| v == value)?;
+    /// The cells of row `y`, left to right.
+    pub fn row(&self, y: i32) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        let start = self.row_start(y);
+        self.data[start..start + self.width as usize].iter()
+    }
+
+    pub fn row_mut(
+        &mut self,
+        y: i32,
+    ) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator + '_ {
+        let start = self.row_start(y);
+        self.data[start..start + self.width as usize].iter_mut()
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn column(&self, x: i32) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        let (start, step) = self.column_start(x);
+        self.data[start..].iter().step_by(step)
+    }
+
+    pub fn column_mut(
+        &mut self,
+        x: i32,
+    ) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator + '_ {
+        let (start, step) = self.column_start(x);
+        self.data[start..].iter_mut().step_by(step)
+    }
+
+    fn row_start(&self, y: i32) -> usize {
+        assert!(
+            (0..self.height).contains(&y),
+            "row {} is outside the grid",
+            y
+        );
+        (y * self.width) as usize
+    }
+
+    fn column_start(&self, x: i32) -> (usize, usize) {
+        assert!(
+            (0..self.width).contains(&x),
+            "column {} is outside the grid",
+            x
+        );
+        (x as usize, self.width as usize)
+    }
+
+    /// Builds a `width` wide grid from cells given row by row.
+    fn from_cells(width: i32, height: i32, cells: impl Iterator<Item = T>) -> Self {
+        let data: Vec<T> = cells.collect();
+        debug_assert_eq!(data.len(), (width * height) as usize);
+        Self {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Swaps rows for columns, mirroring the grid along its main diagonal.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width).flat_map(|x| self.column(x).cloned());
+        Self::from_cells(self.height, self.width, cells)
+    }
+
+    /// Turns the grid a quarter clockwise, so its left column becomes its top row.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width).flat_map(|x| self.column(x).rev().cloned());
+        Self::from_cells(self.height, self.width, cells)
+    }
+
+    /// Turns the grid a quarter anticlockwise, so its right column becomes its top row.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width).rev().flat_map(|x| self.column(x).cloned());
+        Self::from_cells(self.height, self.width, cells)
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_h(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.height).flat_map(|y| self.row(y).rev().cloned());
+        Self::from_cells(self.width, self.height, cells)
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_v(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.height).rev().flat_map(|y| self.row(y).cloned());
+        Self::from_cells(self.width, self.height, cells)
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        let i = self.data.iter().position(|v| v == value)?;
         Some(Point::new(i as i32 % self.width, i as i32 / self.width))
     }
 
     pub fn in_bounds(&self, point: Point) -> bool {
-        let len = self.data.len() as i32;
-        point.x + point.y * self.width >= 0 && point.x + point.y * self.width < len
+        (0..self.width).contains(&point.x) && (0..self.height).contains(&point.y)
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        self.in_bounds(point)
+            .then(|| (point.y * self.width + point.x) as usize)
+    }
+
+    /// `point` moved onto the grid, as if the grid repeated in every direction. The grid
+    /// mustn't be empty.
+    pub fn wrap(&self, point: Point) -> Point {
+        Point::new(
+            point.x.rem_euclid(self.width),
+            point.y.rem_euclid(self.height),
+        )
+    }
+
+    /// The cells up, down, left and right of `point` that are inside the grid.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbors(point, &ADJACENT, Edges::Clip)
+    }
+
+    /// Like [`Grid::neighbors4`], diagonals included.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbors(point, &ADJACENT_DIAG, Edges::Clip)
+    }
+
+    /// The cells one step from `point` in each of `directions`.
+    pub fn neighbors(
+        &self,
+        point: Point,
+        directions: &'static [Point],
+        edges: Edges,
+    ) -> impl Iterator<Item = (Point, &T)> + '_ {
+        directions.iter().filter_map(move |&direction| {
+            let neighbor = match edges {
+                Edges::Clip => point + direction,
+                Edges::Wrap => self.wrap(point + direction),
+            };
+            Some((neighbor, self.get(neighbor)?))
+        })
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|i| &self.data[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|i| &mut self.data[i])
+    }
+
+    /// Like [`Grid::get`], for when a point outside the grid means the input was wrong.
+    pub fn try_get(&self, point: Point) -> Result<&T> {
+        self.get(point).ok_or_else(|| self.out_of_bounds(point))
+    }
+
+    fn out_of_bounds(&self, point: Point) -> anyhow::Error {
+        anyhow!(
+            "({}, {}) is outside the {}x{} grid",
+            point.x,
+            point.y,
+            self.width,
+            self.height
+        )
     }
 }
 
+/// Panics if the point is outside the grid, rather than wrapping onto another row.
 impl<T> Index<Point> for Grid<T> {
     type Output = T;
 
     fn index(&self, index: Point) -> &Self::Output {
-        &self.data[(index.y * self.width + index.x) as usize]
+        match self.offset(index) {
+            Some(i) => &self.data[i],
+            None => panic!("{}", self.out_of_bounds(index)),
+        }
     }
 }
 
 impl<T> IndexMut<Point> for Grid<T> {
     fn index_mut(&mut self, index: Point) -> &mut Self::Output {
-        &mut self.data[(index.y * self.width + index.x) as usize]
+        match self.offset(index) {
+            Some(i) => &mut self.data[i],
+            None => panic!("{}", self.out_of_bounds(index)),
+        }
     }
 }
 
+/// The char of `line` that byte `x` is part of.
+fn char_at(line: &str, x: usize) -> &str {
+    let start = (0..=x)
+        .rev()
+        .find(|&i| line.is_char_boundary(i))
+        .unwrap_or(0);
+    let len = line[start..].chars().next().map_or(0, char::len_utf8);
+    &line[start..start + len]
+}
+
 pub struct GridIterator<'a, T> {
     grid: &'a Grid<T>,
     index: Point,
 }
 
-impl Iterator for GridIterator<'_, u8> {
-    type Item = (Point, u8);
+impl<'a, T> Iterator for GridIterator<'a, T> {
+    type Item = (Point, &'a T);
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.index.y >= self.grid.height {
             return None;
         }
 
-        let item = Some((self.index, self.grid[self.index]));
+        let item = Some((self.index, &self.grid[self.index]));
         self.index.x += 1;
         if self.index.x >= self.grid.width {
             self.index.x = 0;
@@ -84,14 +320,142 @@ impl Iterator for GridIterator<'_, u8> {
     }
 }
 
-impl Display for Grid<u8> {
+impl<T: Render> Display for Grid<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         for y in 0..self.height {
             for x in 0..self.width {
-                write!(f, "{}", self[Point::new(x, y)] as char)?;
+                write!(f, "{}", self[Point::new(x, y)].render())?;
             }
             writeln!(f)?;
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::parse::ParseError;
+    use itertools::Itertools;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    impl Render for Tile {
+        fn render(&self) -> char {
+            match self {
+                Tile::Open => '.',
+                Tile::Wall => '#',
+            }
+        }
+    }
+
+    fn tile(byte: u8) -> Result<Tile> {
+        match byte {
+            b'.' => Ok(Tile::Open),
+            b'#' => Ok(Tile::Wall),
+            _ => Err(anyhow!("`.` or `#`")),
+        }
+    }
+
+    #[test]
+    fn parses_iterates_and_displays_any_cell() -> Result<()> {
+        let grid = Grid::parse_with("..#\n#..\n", tile)?;
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.find(&Tile::Wall), Some(Point::new(2, 0)));
+        let walls = grid.iter().filter(|(_, &tile)| tile == Tile::Wall);
+        assert_eq!(
+            walls.map(|(point, _)| point).collect_vec(),
+            [Point::new(2, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.to_string(), "..#\n#..\n");
+
+        let error = Grid::parse_with("..#\n#x.\n", tile).unwrap_err();
+        assert!(error.is::<ParseError>());
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected `.` or `#`, found `x`"
+        );
+        let error = Grid::parse_with("..#\n.é\n", tile).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected `.` or `#`, found `é`"
+        );
+        assert_eq!(Grid::parse("")?.data, []);
+        let error = Grid::parse("ab\n\ncd\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected a row of cells, found end of line"
+        );
+        assert!(Grid::parse("\n").is_err());
+        let error = Grid::parse_with("..#\n#.\n", tile).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected a row 3 wide, found `#.`"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn bounds_are_per_axis() -> Result<()> {
+        let mut grid = Grid::parse("ab\ncd\n")?;
+        assert!(grid.in_bounds(Point::new(1, 1)));
+        assert!(!grid.in_bounds(Point::new(-1, 1)));
+        assert!(!grid.in_bounds(Point::new(2, 0)));
+        assert_eq!(grid.get(Point::new(0, 1)), Some(&b'c'));
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+
+        *grid.get_mut(Point::new(1, 0)).unwrap() = b'x';
+        assert_eq!(grid[Point::new(1, 0)], b'x');
+        assert!(grid.get_mut(Point::new(-1, 1)).is_none());
+
+        assert_eq!(grid.try_get(Point::new(1, 1)).unwrap(), &b'd');
+        let error = grid.try_get(Point::new(-1, 1)).unwrap_err();
+        assert_eq!(error.to_string(), "(-1, 1) is outside the 2x2 grid");
+        Ok(())
+    }
+
+    #[test]
+    fn neighbors_clip_or_wrap() -> Result<()> {
+        let grid = Grid::parse("abc\ndef\n")?;
+        let cells = |neighbors: Vec<(Point, &u8)>| {
+            neighbors
+                .into_iter()
+                .map(|(_, &c)| c as char)
+                .collect::<String>()
+        };
+        assert_eq!(cells(grid.neighbors4(Point::new(0, 0)).collect()), "db");
+        assert_eq!(cells(grid.neighbors8(Point::new(1, 1)).collect()), "abcdf");
+        let wrapped = grid.neighbors(Point::new(0, 0), &ADJACENT, Edges::Wrap);
+        assert_eq!(cells(wrapped.collect()), "ddcb");
+        assert_eq!(grid.wrap(Point::new(-1, 5)), Point::new(2, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn transforms_rows_and_columns() -> Result<()> {
+        let mut grid = Grid::parse("abc\ndef\n")?;
+        assert_eq!(
+            grid.row(1).rev().map(|&c| c as char).collect::<String>(),
+            "fed"
+        );
+        assert_eq!(grid.column(2).map(|&c| c as char).collect::<String>(), "cf");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.flip_h().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_v().to_string(), "def\nabc\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+
+        grid.column_mut(1)
+            .rev()
+            .for_each(|c| *c = c.to_ascii_uppercase());
+        grid.row_mut(0).for_each(|c| *c = b'x');
+        assert_eq!(grid.to_string(), "xxx\ndEf\n");
+        Ok(())
+    }
+}
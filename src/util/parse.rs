@@ -0,0 +1,279 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// A parse failure pointing at a 1-based line and column of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub found: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Points at `fragment`, which should be a slice of `src`; anything else points at its start.
+    pub fn at(src: &str, fragment: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = position(src, offset_in(src, fragment).unwrap_or(0));
+        Self {
+            line,
+            column,
+            found: fragment.lines().next().unwrap_or_default().to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Re-anchors an error raised while parsing `src` onto `outer`, which `src` is a slice of.
+    pub fn within(mut self, outer: &str, src: &str) -> Self {
+        if let Some(offset) = offset_in(outer, src) {
+            let (line, column) = position(outer, offset);
+            if self.line == 1 {
+                self.column += column - 1;
+            }
+            self.line += line - 1;
+        }
+        self
+    }
+
+    /// Renders the offending line of `input` with a caret under the unexpected text.
+    pub fn diagnostic(&self, input: &str) -> String {
+        let source_line = input.lines().nth(self.line - 1).unwrap_or_default();
+        let gutter = self.line.to_string().len();
+        let width = self.found.chars().count().max(1);
+
+        format!(
+            "error: {}\n{:gutter$} --> line {}, column {}\n{:gutter$} |\n{} | {}\n{:gutter$} | {:indent$}{}",
+            self.message(),
+            "",
+            self.line,
+            self.column,
+            "",
+            self.line,
+            source_line,
+            "",
+            "",
+            "^".repeat(width),
+            gutter = gutter,
+            indent = self.column - 1,
+        )
+    }
+
+    fn message(&self) -> String {
+        match self.found.as_str() {
+            "" => format!("expected {}, found end of line", self.expected),
+            found => format!("expected {}, found `{}`", self.expected, found),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.column,
+            self.message()
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Renders a caret diagnostic if `error` is a [`ParseError`] raised while parsing `input`.
+pub fn diagnostic(error: &anyhow::Error, input: &str) -> Option<String> {
+    error
+        .downcast_ref::<ParseError>()
+        .map(|error| error.diagnostic(input))
+}
+
+/// Prints the caret diagnostic for `error` to stderr, if it has one.
+pub fn eprint_diagnostic(error: &anyhow::Error, input: &str) {
+    if let Some(diagnostic) = diagnostic(error, input) {
+        eprintln!("{}", diagnostic);
+    }
+}
+
+fn offset_in(src: &str, fragment: &str) -> Option<usize> {
+    let offset = (fragment.as_ptr() as usize).checked_sub(src.as_ptr() as usize)?;
+    (offset <= src.len()).then_some(offset)
+}
+
+fn position(src: &str, offset: usize) -> (usize, usize) {
+    let before = &src[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+/// The text being parsed, used to locate the fragments that fail to parse.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a>(pub &'a str);
+
+impl<'a> Source<'a> {
+    pub fn error(&self, fragment: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at(self.0, fragment, expected)
+    }
+
+    pub fn strip_prefix<'s>(&self, s: &'s str, prefix: &str) -> Result<&'s str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("`{}`", prefix)))
+    }
+
+    pub fn strip_suffix<'s>(&self, s: &'s str, suffix: &str) -> Result<&'s str, ParseError> {
+        s.strip_suffix(suffix).ok_or_else(|| {
+            let last = s.char_indices().next_back().map_or("", |(i, _)| &s[i..]);
+            self.error(last, format!("`{}`", suffix))
+        })
+    }
+
+    pub fn split_once<'s>(
+        &self,
+        s: &'s str,
+        delimiter: &str,
+    ) -> Result<(&'s str, &'s str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("`{}`", delimiter)))
+    }
+
+    pub fn parse<T: FromStr>(&self, s: &str, expected: &str) -> Result<T, ParseError> {
+        s.parse().map_err(|_| self.error(s, expected))
+    }
+
+    /// Takes exactly `N` of `fields`, which are slices of `s`, pointing past the end of `s`
+    /// when one is missing and at the first extra field when there are too many.
+    pub fn fields<'s, const N: usize>(
+        &self,
+        s: &'s str,
+        fields: impl IntoIterator<Item = &'s str>,
+        expected: &str,
+    ) -> Result<[&'s str; N], ParseError> {
+        let mut fields = fields.into_iter();
+        let mut taken = [""; N];
+        for field in &mut taken {
+            *field = fields
+                .next()
+                .ok_or_else(|| self.error(&s[s.len()..], expected))?;
+        }
+        match fields.next() {
+            Some(extra) => Err(self.error(extra, "end of field list")),
+            None => Ok(taken),
+        }
+    }
+
+    /// Parses exactly `N` of `fields` as `T`; see [`Source::fields`].
+    pub fn parse_fields<'s, T: FromStr, const N: usize>(
+        &self,
+        s: &'s str,
+        fields: impl IntoIterator<Item = &'s str>,
+        expected: &str,
+    ) -> Result<[T; N], ParseError> {
+        let parsed = self
+            .fields::<N>(s, fields, expected)?
+            .into_iter()
+            .map(|field| self.parse(field, expected))
+            .collect::<Result<Vec<T>, _>>()?;
+        match parsed.try_into() {
+            Ok(parsed) => Ok(parsed),
+            Err(_) => unreachable!("exactly {} fields were taken", N),
+        }
+    }
+
+    /// Re-anchors a [`ParseError`] raised while parsing `part` onto this source, and labels
+    /// any other error with the line it happened on.
+    pub fn within(&self, error: anyhow::Error, part: &str) -> anyhow::Error {
+        match error.downcast::<ParseError>() {
+            Ok(error) => error.within(self.0, part).into(),
+            Err(error) => {
+                let (line, _) = position(self.0, offset_in(self.0, part).unwrap_or(0));
+                error.context(format!("on line {}", line))
+            }
+        }
+    }
+
+    /// Parses each of `parts`, which are slices of this source, keeping errors anchored to it.
+    pub fn parse_each<T>(
+        &self,
+        parts: impl IntoIterator<Item = &'a str>,
+        mut parser: impl FnMut(&'a str) -> anyhow::Result<T>,
+    ) -> anyhow::Result<Vec<T>> {
+        parts
+            .into_iter()
+            .map(|part| parser(part).map_err(|error| self.within(error, part)))
+            .collect()
+    }
+
+    pub fn lines<T>(
+        &self,
+        parser: impl FnMut(&'a str) -> anyhow::Result<T>,
+    ) -> anyhow::Result<Vec<T>> {
+        self.parse_each(self.0.lines(), parser)
+    }
+}
+
+/// Parses every line of `src` with `FromStr`, anchoring parse errors to their line.
+pub fn parse_lines<T>(src: &str) -> anyhow::Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<anyhow::Error>,
+{
+    Source(src).lines(|line| line.parse::<T>().map_err(Into::into))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_fragment() {
+        let input = "seeds: 1 2\n\nfoo: 3 x 5\n";
+        let bad = &input[19..20];
+        let error = ParseError::at(input, bad, "a number");
+
+        assert_eq!((error.line, error.column), (3, 8));
+        assert_eq!(error.found, "x");
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 8: expected a number, found `x`"
+        );
+        assert_eq!(
+            error.diagnostic(input),
+            "error: expected a number, found `x`\n  --> line 3, column 8\n  |\n3 | foo: 3 x 5\n  |        ^"
+        );
+    }
+
+    #[test]
+    fn strips_suffix_after_multibyte_char() {
+        let input = "a=(1é";
+        let error = Source(input).strip_suffix(input, ")").unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (5, "é"));
+        assert!(Source("").strip_suffix("", ")").is_err());
+    }
+
+    #[test]
+    fn rebases_line_errors() {
+        let input = "a=1\nb=2\nc=x\n";
+        let result = parse_lines::<Assignment>(input);
+        let error = result.unwrap_err().downcast::<ParseError>().unwrap();
+
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.expected, "a number");
+    }
+
+    #[derive(Debug)]
+    struct Assignment;
+
+    impl FromStr for Assignment {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let src = Source(s);
+            let (_, value) = src.split_once(s, "=")?;
+            src.parse::<u32>(value, "a number")?;
+            Ok(Assignment)
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The way a search found to its goal.
+#[derive(Debug, Clone)]
+pub struct Found<S, C> {
+    pub cost: C,
+    /// The states from the start to the goal, both included.
+    pub path: Vec<S>,
+    /// Every state reached (or, for [`dijkstra`] and [`astar`], settled), mapped to the one
+    /// it was reached from on the cheapest way to it. The start has none.
+    pub predecessors: HashMap<S, S>,
+}
+
+impl<S: Clone + Eq + Hash, C> Found<S, C> {
+    fn new(goal: S, cost: C, predecessors: HashMap<S, S>) -> Self {
+        let mut path = vec![goal];
+        while let Some(previous) = predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        Self {
+            cost,
+            path,
+            predecessors,
+        }
+    }
+}
+
+/// The fewest steps from `start` to a state that `is_goal`, taking every step to one of the
+/// `successors` of a state.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut steps = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors = HashMap::new();
+    let mut frontier = VecDeque::from([start]);
+
+    while let Some(state) = frontier.pop_front() {
+        let cost = steps[&state];
+        if is_goal(&state) {
+            return Some(Found::new(state, cost, predecessors));
+        }
+        for next in successors(&state) {
+            if steps.contains_key(&next) {
+                continue;
+            }
+            steps.insert(next.clone(), cost + 1);
+            predecessors.insert(next.clone(), state.clone());
+            frontier.push_back(next);
+        }
+    }
+
+    None
+}
+
+/// The cheapest way from `start` to a state that `is_goal`, where `successors` gives the
+/// states a state leads to along with what each step costs. Costs mustn't be negative, and
+/// `C::default()` is a cost of nothing.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but trying states first whose `heuristic` says they're closer to a
+/// goal. The heuristic must never overestimate the cost left, nor drop by more than a step
+/// costs, or the way found might not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // States are settled when first popped, which is the cheapest way to them, so the heap
+    // may hold a state more than once rather than be updated in place
+    let mut predecessors = HashMap::new();
+    let mut frontier = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start.clone(),
+        previous: None,
+    }]);
+
+    while let Some(Queued {
+        cost,
+        state,
+        previous,
+        ..
+    }) = frontier.pop()
+    {
+        if let Some(previous) = previous {
+            match predecessors.entry(state.clone()) {
+                Entry::Occupied(_) => continue,
+                Entry::Vacant(entry) => entry.insert(previous),
+            };
+        }
+        if is_goal(&state) {
+            return Some(Found::new(state, cost, predecessors));
+        }
+        for (next, step) in successors(&state) {
+            if next == start {
+                continue; // settled first, and never a predecessor
+            }
+            let cost = cost + step;
+            frontier.push(Queued {
+                priority: cost + heuristic(&next),
+                cost,
+                state: next,
+                previous: Some(state.clone()),
+            });
+        }
+    }
+
+    None
+}
+
+/// A state waiting in the frontier, ordered so that the max-heap pops the lowest priority.
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+    previous: Option<S>,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{grid::Grid, point::Point};
+
+    #[test]
+    fn all_find_the_cheapest_path() -> anyhow::Result<()> {
+        // Digits cost what they say to step onto; the cheap way goes around the 9s
+        let grid = Grid::parse("1191\n1191\n1111\n")?;
+        let start = Point::new(0, 0);
+        let end = Point::new(3, 0);
+        let is_end = |&point: &Point| point == end;
+        let weighted = |&point: &Point| {
+            let neighbors = grid.neighbors4(point);
+            neighbors
+                .map(|(next, &c)| (next, (c - b'0') as u32))
+                .collect::<Vec<_>>()
+        };
+
+        let found = dijkstra(start, weighted, is_end).unwrap();
+        assert_eq!(found.cost, 7);
+        assert_eq!(found.path.len(), 8);
+        assert_eq!(found.path.first(), Some(&start));
+        assert_eq!(found.predecessors[&end], Point::new(3, 1));
+
+        let heuristic = |point: &Point| point.manhattan_distance(end) as u32;
+        let found = astar(start, weighted, heuristic, is_end).unwrap();
+        assert_eq!(found.cost, 7);
+
+        let unweighted = |&point: &Point| grid.neighbors4(point).map(|(next, _)| next);
+        let found = bfs(start, unweighted, is_end).unwrap();
+        assert_eq!((found.cost, found.path.len()), (3, 4));
+        assert!(bfs(start, unweighted, |_| false).is_none());
+        Ok(())
+    }
+}
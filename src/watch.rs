@@ -0,0 +1,236 @@
+use anyhow::*;
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    options::Part,
+    runner::{self, Registration, Report},
+    util::parse::diagnostic,
+};
+
+/// How often `aoc watch` checks its files for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A file being watched: when it last changed, and what it was solved to then.
+#[derive(Debug)]
+struct Watched {
+    path: PathBuf,
+    stamp: Option<(SystemTime, u64)>,
+    answers: Option<Vec<(Part, Result<String, String>)>>,
+}
+
+/// Re-solves a day whenever one of its inputs changes on disk.
+///
+/// The embedded input and samples are compiled in, so the files are read at runtime instead:
+/// the day's `input.txt` (or the one given) and every `sample*.txt` next to it. Changes to the
+/// solution itself still need a rebuild.
+#[derive(Debug)]
+pub struct Watcher<'a> {
+    registration: &'a Registration,
+    part: Option<Part>,
+    timeout: Option<Duration>,
+    files: Vec<Watched>,
+}
+
+impl<'a> Watcher<'a> {
+    /// Watches `registration`'s files in `root`'s `src/days`, with `input` in place of its
+    /// `input.txt` if given.
+    pub fn new(
+        root: &Path,
+        registration: &'a Registration,
+        input: Option<PathBuf>,
+        part: Option<Part>,
+        timeout: Option<Duration>,
+    ) -> Result<Self> {
+        let dir = root.join("src/days").join(registration.name());
+        let entries = fs::read_dir(&dir)
+            .with_context(|| format!("Failed to list {}", dir.display()))?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<Result<Vec<_>>>()?;
+        let mut samples = entries
+            .into_iter()
+            .filter(|path| {
+                let name = path.file_name().and_then(|name| name.to_str());
+                name.is_some_and(|name| name.starts_with("sample") && name.ends_with(".txt"))
+            })
+            .collect::<Vec<_>>();
+        samples.sort();
+
+        let input = input.unwrap_or_else(|| dir.join("input.txt"));
+        let files = [input]
+            .into_iter()
+            .chain(samples)
+            .map(|path| Watched {
+                path,
+                stamp: None,
+                answers: None,
+            })
+            .collect();
+
+        Ok(Self {
+            registration,
+            part,
+            timeout,
+            files,
+        })
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.iter().map(|file| file.path.as_path())
+    }
+
+    /// Re-solves every file that changed since the last poll, which is all of them the first
+    /// time, and describes what changed; empty if nothing did. With spans enabled, each
+    /// solve's tree follows its answers.
+    pub fn poll(&mut self) -> String {
+        let mut output = String::new();
+        for file in &mut self.files {
+            let stamp = fs::metadata(&file.path)
+                .and_then(|metadata| std::io::Result::Ok((metadata.modified()?, metadata.len())))
+                .ok();
+            if file.answers.is_some() && stamp == file.stamp {
+                continue;
+            }
+            file.stamp = stamp;
+
+            let name = file.path.file_name().unwrap_or_default().to_string_lossy();
+            writeln!(output, "{}", name).unwrap();
+            let data = match fs::read_to_string(&file.path) {
+                Result::Ok(data) => data,
+                Err(error) => {
+                    // Keep what it solved to last, to compare against once it's back
+                    writeln!(output, "  error: Failed to read it: {}", error).unwrap();
+                    file.answers.get_or_insert_with(Vec::new);
+                    continue;
+                }
+            };
+
+            let report = self.registration.run(&data, self.part, self.timeout);
+            let answers = answers(&report, self.part);
+            output += &changes(&report, file.answers.as_deref(), &answers);
+            file.answers = Some(answers);
+            match &report {
+                Result::Ok(report) => {
+                    output += &runner::span_tree(&self.registration.name(), report);
+                }
+                Err(error) => {
+                    if let Some(diagnostic) = diagnostic(error, &data) {
+                        writeln!(output, "{}", diagnostic).unwrap();
+                    }
+                }
+            }
+        }
+        output
+    }
+}
+
+fn answers(report: &Result<Report>, part: Option<Part>) -> Vec<(Part, Result<String, String>)> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![Part::A, Part::B],
+    };
+    parts
+        .into_iter()
+        .map(|part| {
+            let answer = match report {
+                Err(error) => Err(error.to_string()),
+                Result::Ok(report) => match report.parts.iter().find(|p| p.part == part) {
+                    Some(report) => report.answer.as_ref().cloned().map_err(|e| e.to_string()),
+                    None => Err("not run".to_string()),
+                },
+            };
+            (part, answer)
+        })
+        .collect()
+}
+
+/// One line per part, showing the previous answer before an arrow where it changed.
+fn changes(
+    report: &Result<Report>,
+    previous: Option<&[(Part, Result<String, String>)]>,
+    answers: &[(Part, Result<String, String>)],
+) -> String {
+    let show = |answer: &Result<String, String>| match answer {
+        Result::Ok(answer) => answer.clone(),
+        Err(error) => format!("error: {}", error),
+    };
+
+    let mut lines = String::new();
+    let report = match report {
+        Result::Ok(report) => report,
+        Err(error) => {
+            writeln!(lines, "  error: {}", error).unwrap();
+            return lines;
+        }
+    };
+    writeln!(lines, "  parsed in {:?}", report.parse_time).unwrap();
+
+    for (part, answer) in answers {
+        let label = match part {
+            Part::A => "part 1",
+            Part::B => "part 2",
+        };
+        let before = previous
+            .and_then(|previous| previous.iter().find(|(p, _)| p == part))
+            .map(|(_, answer)| answer);
+        let answer = match before {
+            Some(before) if before != answer => format!("{} → {}", show(before), show(answer)),
+            _ => show(answer),
+        };
+        let time = report.parts.iter().find(|p| p.part == *part);
+        let time = time.map(|p| format!(" ({:?})", p.time)).unwrap_or_default();
+        writeln!(lines, "  {}: {}{}", label, answer, time).unwrap();
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days, runner::PartReport};
+
+    #[test]
+    fn watches_input_and_samples() -> Result<()> {
+        let registry = days::registry();
+        let day01 = runner::select(&registry, "1")?[0];
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let watcher = Watcher::new(root, day01, None, None, None)?;
+        let names = watcher
+            .paths()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["input.txt", "sample1.txt", "sample2.txt"]);
+        Ok(())
+    }
+
+    #[test]
+    fn shows_changed_answers() {
+        let report = Result::Ok(Report {
+            parse_time: Duration::from_micros(5),
+            parse_memory: None,
+            parse_spans: vec![],
+            parts: vec![PartReport {
+                part: Part::B,
+                answer: Result::Ok("65".to_string()),
+                time: Duration::from_millis(2),
+                memory: None,
+                spans: vec![],
+                cached: false,
+            }],
+        });
+        let answers = answers(&report, Some(Part::B));
+        assert_eq!(
+            changes(&report, None, &answers),
+            "  parsed in 5µs\n  part 2: 65 (2ms)\n"
+        );
+        let previous = [(Part::B, Result::Ok("64".to_string()))];
+        assert_eq!(
+            changes(&report, Some(&previous), &answers),
+            "  parsed in 5µs\n  part 2: 64 → 65 (2ms)\n"
+        );
+    }
+}